@@ -0,0 +1,78 @@
+use crate::board::BOARD_WIDTH;
+use crate::board::bitboard::magic;
+use crate::moves::{Delta, KING_DELTAS, KNIGHT_DELTAS};
+
+pub(crate) const KNIGHT_ATTACKS: [u64; 64] = leaper_attacks(&KNIGHT_DELTAS);
+pub(crate) const KING_ATTACKS: [u64; 64] = leaper_attacks(&KING_DELTAS);
+/// Squares attacked by a pawn standing on a square, indexed by color and then by square.
+pub(crate) const PAWN_ATTACKS: [[u64; 64]; 2] = [
+    leaper_attacks(&[(-1, 1), (1, 1)]),
+    leaper_attacks(&[(-1, -1), (1, -1)]),
+];
+
+#[inline]
+pub(crate) fn rook_attacks(square: usize, occupied: u64) -> u64 {
+    return magic::rook_attacks(square, occupied)
+}
+
+#[inline]
+pub(crate) fn bishop_attacks(square: usize, occupied: u64) -> u64 {
+    return magic::bishop_attacks(square, occupied)
+}
+
+/// Squares strictly between `from` and `to` when they share a row, column or diagonal, otherwise none.
+#[inline]
+pub(crate) fn between(from: usize, to: usize) -> u64 {
+    let from_bit = 1u64 << from;
+    let to_bit = 1u64 << to;
+    return if rook_attacks(from, 0) & to_bit != 0 {
+        rook_attacks(from, to_bit) & rook_attacks(to, from_bit)
+    } else if bishop_attacks(from, 0) & to_bit != 0 {
+        bishop_attacks(from, to_bit) & bishop_attacks(to, from_bit)
+    } else {
+        0
+    }
+}
+
+/// Steps from `square` in every direction until the edge of the board or the first occupied square,
+/// which is included in the result. Slow, but the magic tables are built from it.
+pub(crate) fn ray_attacks(square: usize, occupied: u64, directions: &[Delta]) -> u64 {
+    let mut attacks = 0;
+    for (delta_x, delta_y) in directions {
+        let mut x = (square % BOARD_WIDTH) as isize + delta_x;
+        let mut y = (square / BOARD_WIDTH) as isize + delta_y;
+        while is_on_board(x, y) {
+            let bit = 1u64 << (y as usize * BOARD_WIDTH + x as usize);
+            attacks |= bit;
+            if occupied & bit != 0 {
+                break;
+            }
+            x += delta_x;
+            y += delta_y;
+        }
+    }
+    return attacks
+}
+
+const fn leaper_attacks(deltas: &[Delta]) -> [u64; 64] {
+    let mut attacks = [0u64; 64];
+    let mut square = 0;
+    while square < 64 {
+        let mut i = 0;
+        while i < deltas.len() {
+            let x = (square % BOARD_WIDTH) as isize + deltas[i].0;
+            let y = (square / BOARD_WIDTH) as isize + deltas[i].1;
+            if is_on_board(x, y) {
+                attacks[square] |= 1u64 << (y as usize * BOARD_WIDTH + x as usize);
+            }
+            i += 1;
+        }
+        square += 1;
+    }
+    return attacks
+}
+
+#[inline]
+const fn is_on_board(x: isize, y: isize) -> bool {
+    return x >= 0 && x < BOARD_WIDTH as isize && y >= 0 && y < BOARD_WIDTH as isize
+}
@@ -0,0 +1,146 @@
+use std::sync::OnceLock;
+use crate::board::BOARD_WIDTH;
+use crate::board::bitboard::attack_tables::ray_attacks;
+use crate::moves::{Delta, DIAGONAL_DIRECTIONS, STRAIGHT_DIRECTIONS};
+
+/// Perfect hash from the pieces on the rays of a square to its slot in the shared attack table:
+/// `offset + ((occupied & mask) * magic) >> shift`.
+struct Magic {
+    mask: u64,
+    magic: u64,
+    shift: u32,
+    offset: usize,
+}
+
+impl Magic {
+    #[inline]
+    fn index(&self, occupied: u64) -> usize {
+        return self.offset + ((occupied & self.mask).wrapping_mul(self.magic) >> self.shift) as usize
+    }
+}
+
+struct SliderTables {
+    rook: Vec<Magic>,
+    bishop: Vec<Magic>,
+    attacks: Vec<u64>,
+}
+
+static SLIDER_TABLES: OnceLock<SliderTables> = OnceLock::new();
+
+/// Tables are searched for on first use, which takes a moment once per process.
+#[inline]
+fn tables() -> &'static SliderTables {
+    return SLIDER_TABLES.get_or_init(SliderTables::generate)
+}
+
+#[inline]
+pub(crate) fn rook_attacks(square: usize, occupied: u64) -> u64 {
+    let tables = tables();
+    return tables.attacks[tables.rook[square].index(occupied)]
+}
+
+#[inline]
+pub(crate) fn bishop_attacks(square: usize, occupied: u64) -> u64 {
+    let tables = tables();
+    return tables.attacks[tables.bishop[square].index(occupied)]
+}
+
+impl SliderTables {
+    fn generate() -> SliderTables {
+        let mut attacks = Vec::new();
+        let rook = (0..64)
+            .map(|square| find_magic(square, &STRAIGHT_DIRECTIONS, &mut attacks))
+            .collect();
+        let bishop = (0..64)
+            .map(|square| find_magic(square, &DIAGONAL_DIRECTIONS, &mut attacks))
+            .collect();
+        return SliderTables {rook, bishop, attacks}
+    }
+}
+
+/// Tries random sparse numbers until one maps every blocker set of `square` to a slot holding
+/// the right attack set, then appends those slots to `attacks`.
+fn find_magic(square: usize, directions: &[Delta], attacks: &mut Vec<u64>) -> Magic {
+    let mask = relevant_blockers(square, directions);
+    let bits = mask.count_ones();
+    let shift = 64 - bits;
+
+    let mut blocker_sets = Vec::with_capacity(1 << bits);
+    let mut subset: u64 = 0;
+    loop {
+        blocker_sets.push((subset, ray_attacks(square, subset, directions)));
+        subset = subset.wrapping_sub(mask) & mask;
+        if subset == 0 {
+            break;
+        }
+    }
+
+    let mut rng = XorShift(SEEDS[square / BOARD_WIDTH]);
+    let mut table = vec![0u64; 1 << bits];
+    let mut used_in_try = vec![0u32; 1 << bits];
+    let mut try_number = 0;
+    loop {
+        let magic = rng.next() & rng.next() & rng.next();
+        if (mask.wrapping_mul(magic) >> 56).count_ones() < 6 {
+            continue;
+        }
+
+        try_number += 1;
+        let fits = blocker_sets.iter().all(|(blockers, blocker_attacks)| {
+            let index = (blockers.wrapping_mul(magic) >> shift) as usize;
+            if used_in_try[index] != try_number {
+                used_in_try[index] = try_number;
+                table[index] = *blocker_attacks;
+                return true
+            }
+            return table[index] == *blocker_attacks
+        });
+
+        if fits {
+            let offset = attacks.len();
+            attacks.extend_from_slice(&table);
+            return Magic {mask, magic, shift, offset}
+        }
+    }
+}
+
+/// Squares on the rays of `square` whose occupancy matters, which leaves out the last square
+/// before the edge of the board.
+fn relevant_blockers(square: usize, directions: &[Delta]) -> u64 {
+    let mut mask = 0;
+    for (delta_x, delta_y) in directions {
+        let mut x = (square % BOARD_WIDTH) as isize + delta_x;
+        let mut y = (square / BOARD_WIDTH) as isize + delta_y;
+        while is_inner(x, delta_x) && is_inner(y, delta_y) {
+            mask |= 1u64 << (y as usize * BOARD_WIDTH + x as usize);
+            x += delta_x;
+            y += delta_y;
+        }
+    }
+    return mask
+}
+
+/// Coordinate is on the board and, when moving along it, not on the edge yet.
+#[inline]
+fn is_inner(coordinate: isize, delta: &isize) -> bool {
+    return match delta {
+        0 => (0..BOARD_WIDTH as isize).contains(&coordinate),
+        _ => (1..BOARD_WIDTH as isize - 1).contains(&coordinate),
+    }
+}
+
+/// Seeds per row which are known to find magic numbers after few tries.
+const SEEDS: [u64; BOARD_WIDTH] = [728, 10316, 55079, 32803, 12281, 15100, 16645, 255];
+
+/// Xorshift64* generator.
+struct XorShift(u64);
+
+impl XorShift {
+    #[inline]
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        return self.0.wrapping_mul(2685821657736338717)
+    }
+}
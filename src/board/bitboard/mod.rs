@@ -0,0 +1,227 @@
+use crate::board::{Board, BOARD_WIDTH, Field, Position, zobrist};
+use crate::board::castling_rights::CastlingRights;
+use crate::board::piece::{Color, PieceMoved, PieceState, Type};
+use crate::board::bitboard::attack_tables::PAWN_ATTACKS;
+use crate::moves::{Move, MoveFlag};
+
+pub(crate) mod attack_tables;
+mod magic;
+
+#[cfg(test)]
+mod tests;
+
+/// Board kept as one `u64` per piece type and color, where bit `y * 8 + x` is set when such
+/// a piece stands on `Position {x, y}`.
+/// Unlike [`Board`] it does not remember which pieces have moved, castling rights and
+/// the en passant square are kept for the whole board instead.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BitBoard {
+    pieces: [[u64; 6]; 2],
+    colors: [u64; 2],
+    castling_rights: CastlingRights,
+    en_passant: Option<usize>,
+}
+
+impl BitBoard {
+    pub fn new() -> BitBoard {
+        return BitBoard::from_board(&Board::new())
+    }
+
+    pub fn from_board(board: &Board) -> BitBoard {
+        let mut bit_board = BitBoard {
+            pieces: [[0; 6]; 2],
+            colors: [0; 2],
+            castling_rights: board.castling_rights(),
+            en_passant: board.en_passant_target().map(|pos| pos.as_board_index()),
+        };
+        for color in [Color::White, Color::Black] {
+            for pos in board.positions_of(&color) {
+                if let Some(state) = board.piece_at(&pos) {
+                    bit_board.put(pos.as_board_index(), state.piece_type, state.color);
+                }
+            }
+        }
+        return bit_board
+    }
+
+    /// Mailbox board with the same pieces, the moved state of each piece is worked out
+    /// the same way as in [`BitBoard::piece_at`].
+    pub fn to_board(&self) -> Board {
+        let mut fields = Vec::new();
+        for square in BitIter(self.occupied()) {
+            let pos = Position::from_board_index(square);
+            if let Some(state) = self.piece_at(&pos) {
+                fields.push((Field::Piece(state), pos));
+            }
+        }
+        return Board::new_from(fields)
+    }
+}
+
+impl Default for BitBoard {
+    fn default() -> Self {
+        return BitBoard::new()
+    }
+}
+
+impl BitBoard {
+    #[inline]
+    pub fn value_at(&self, pos: &Position) -> Field {
+        return match self.piece_at(pos) {
+            Some(state) => Field::Piece(state),
+            None => Field::Empty,
+        }
+    }
+
+    /// Piece standing on `pos`. Pieces do not carry their history here, so `moved` is `No` only for
+    /// pawns on their start row and for kings and rooks which can still castle, and `LastTurn`
+    /// for a pawn which can be taken en passant.
+    pub fn piece_at(&self, pos: &Position) -> Option<PieceState> {
+        let square = pos.as_board_index();
+        let color = self.color_at(square)?;
+        let piece_type = self.type_at(square, color)?;
+        return Some(PieceState {piece_type, color, moved: self.derive_moved(pos, piece_type, &color)})
+    }
+
+    #[inline]
+    pub fn is_empty(&self, pos: &Position) -> bool {
+        return self.occupied() & (1 << pos.as_board_index()) == 0
+    }
+
+    #[inline]
+    pub fn is_color(&self, pos: &Position, color: &Color) -> bool {
+        return self.colors[color.index()] & (1 << pos.as_board_index()) != 0
+    }
+
+    #[inline]
+    pub fn castling_rights(&self) -> CastlingRights {
+        return self.castling_rights
+    }
+
+    #[inline]
+    pub fn en_passant_target(&self) -> Option<Position> {
+        return self.en_passant.map(Position::from_board_index)
+    }
+
+    /// Set of squares with pieces of `piece_type` and `color`.
+    #[inline]
+    pub fn pieces(&self, piece_type: Type, color: &Color) -> u64 {
+        return self.pieces[color.index()][piece_type.index()]
+    }
+
+    #[inline]
+    pub fn color_set(&self, color: &Color) -> u64 {
+        return self.colors[color.index()]
+    }
+
+    #[inline]
+    pub fn occupied(&self) -> u64 {
+        return self.colors[0] | self.colors[1]
+    }
+
+    #[inline]
+    pub(crate) fn color_at(&self, square: usize) -> Option<Color> {
+        let bit = 1 << square;
+        return if self.colors[Color::White.index()] & bit != 0 {
+            Some(Color::White)
+        } else if self.colors[Color::Black.index()] & bit != 0 {
+            Some(Color::Black)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub(crate) fn type_at(&self, square: usize, color: Color) -> Option<Type> {
+        let bit = 1 << square;
+        return Type::ALL.into_iter().find(|piece_type| self.pieces[color.index()][piece_type.index()] & bit != 0)
+    }
+
+    #[inline]
+    fn derive_moved(&self, pos: &Position, piece_type: Type, color: &Color) -> PieceMoved {
+        return Board::derive_moved(pos, piece_type, color, &self.castling_rights, self.en_passant_target())
+    }
+
+    /// Same key as [`Board::zobrist_key`], worked out from scratch as a bit board does not keep one.
+    pub fn zobrist_key(&self, side_to_move: &Color) -> u64 {
+        let mut key = zobrist::castling_key(&self.castling_rights) ^ zobrist::turn_key(side_to_move);
+        for color in [Color::White, Color::Black] {
+            for piece_type in Type::ALL {
+                for square in BitIter(self.pieces(piece_type, &color)) {
+                    key ^= zobrist::piece_key(piece_type, color, square);
+                }
+            }
+        }
+        if let Some(target) = self.en_passant {
+            let capturer = if target / BOARD_WIDTH < BOARD_WIDTH / 2 { Color::Black } else { Color::White };
+            if PAWN_ATTACKS[capturer.opposite().index()][target] & self.pieces(Type::Pawn, &capturer) != 0 {
+                key ^= zobrist::en_passant_key(target % BOARD_WIDTH);
+            }
+        }
+        return key
+    }
+}
+
+impl BitBoard {
+    /// Plays `mv`, updating castling rights and the en passant square along the way.
+    pub fn make_move(&mut self, mv: &Move) {
+        let from = mv.from.as_board_index();
+        let to = mv.to.as_board_index();
+        let color = mv.piece.color;
+
+        let captured_square = Move::captured_pos(&mv.from, &mv.to, mv.flag).as_board_index();
+        if let Some(captured_color) = self.color_at(captured_square) {
+            if let Some(captured_type) = self.type_at(captured_square, captured_color) {
+                self.remove(captured_square, captured_type, captured_color);
+            }
+        }
+
+        self.remove(from, mv.piece.piece_type, color);
+        self.put(to, mv.promotion.unwrap_or(mv.piece.piece_type), color);
+
+        if mv.is_castling() {
+            let row = Board::start_row(&color) * BOARD_WIDTH;
+            let (rook_from, rook_to) = match mv.flag {
+                MoveFlag::KingSideCastle => (Board::KING_SIDE_ROOK_X, Board::KING_START_X + 1),
+                _ => (Board::QUEEN_SIDE_ROOK_X, Board::KING_START_X - 1),
+            };
+            self.remove(row + rook_from, Type::Rook, color);
+            self.put(row + rook_to, Type::Rook, color);
+        }
+
+        self.en_passant = match mv.flag {
+            MoveFlag::DoublePawnPush => Some((from + to) / 2),
+            _ => None,
+        };
+        self.castling_rights.update(mv);
+    }
+
+    #[inline]
+    fn put(&mut self, square: usize, piece_type: Type, color: Color) {
+        self.pieces[color.index()][piece_type.index()] |= 1 << square;
+        self.colors[color.index()] |= 1 << square;
+    }
+
+    #[inline]
+    fn remove(&mut self, square: usize, piece_type: Type, color: Color) {
+        self.pieces[color.index()][piece_type.index()] &= !(1 << square);
+        self.colors[color.index()] &= !(1 << square);
+    }
+}
+
+/// Iterates over indexes of the set bits, lowest first.
+pub(crate) struct BitIter(pub(crate) u64);
+
+impl Iterator for BitIter {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.0 == 0 {
+            return None
+        }
+        let square = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        return Some(square)
+    }
+}
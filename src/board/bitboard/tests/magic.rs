@@ -0,0 +1,23 @@
+use crate::board::bitboard::attack_tables::{bishop_attacks, ray_attacks, rook_attacks};
+use crate::moves::{DIAGONAL_DIRECTIONS, STRAIGHT_DIRECTIONS};
+use crate::test_utils::TestRng;
+
+#[test]
+fn test_magic_lookup_matches_rays() {
+    let mut rng = TestRng(0xD1B54A32D192ED03);
+    for square in 0..64 {
+        for _ in 0..200 {
+            let occupied = rng.next() & rng.next();
+            assert_eq!(ray_attacks(square, occupied, &STRAIGHT_DIRECTIONS), rook_attacks(square, occupied));
+            assert_eq!(ray_attacks(square, occupied, &DIAGONAL_DIRECTIONS), bishop_attacks(square, occupied));
+        }
+    }
+}
+
+#[test]
+fn test_sliders_on_empty_and_full_board() {
+    assert_eq!(0x01010101010101FE, rook_attacks(0, 0));
+    assert_eq!(0x8040201008040200, bishop_attacks(0, 0));
+    assert_eq!(0x0000000000000102, rook_attacks(0, u64::MAX));
+    assert_eq!(0x0000001400140000, bishop_attacks(27, u64::MAX));
+}
@@ -0,0 +1,65 @@
+use crate::board::Board;
+use crate::board::piece::{Color, Type};
+use crate::moves::Move;
+
+/// Which castlings are still possible in the game, no matter whether they are playable right now.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct CastlingRights {
+    pub white_king_side: bool,
+    pub white_queen_side: bool,
+    pub black_king_side: bool,
+    pub black_queen_side: bool,
+}
+
+impl CastlingRights {
+    pub const ALL: CastlingRights = CastlingRights {
+        white_king_side: true,
+        white_queen_side: true,
+        black_king_side: true,
+        black_queen_side: true,
+    };
+
+    #[inline]
+    pub fn king_side(&self, color: &Color) -> bool {
+        return match color {
+            Color::White => self.white_king_side,
+            Color::Black => self.black_king_side,
+        }
+    }
+
+    #[inline]
+    pub fn queen_side(&self, color: &Color) -> bool {
+        return match color {
+            Color::White => self.white_queen_side,
+            Color::Black => self.black_queen_side,
+        }
+    }
+}
+
+impl CastlingRights {
+    /// Drops the rights `mv` loses by moving the king, or by moving or taking a rook in its corner.
+    pub(crate) fn update(&mut self, mv: &Move) {
+        if mv.piece.piece_type == Type::KING {
+            match mv.piece.color {
+                Color::White => {
+                    self.white_king_side = false;
+                    self.white_queen_side = false;
+                }
+                Color::Black => {
+                    self.black_king_side = false;
+                    self.black_queen_side = false;
+                }
+            }
+        }
+
+        for pos in [&mv.from, &mv.to] {
+            match (pos.x, pos.y) {
+                (Board::QUEEN_SIDE_ROOK_X, Board::WHITE_START_ROW) => self.white_queen_side = false,
+                (Board::KING_SIDE_ROOK_X, Board::WHITE_START_ROW) => self.white_king_side = false,
+                (Board::QUEEN_SIDE_ROOK_X, Board::BLACK_START_ROW) => self.black_queen_side = false,
+                (Board::KING_SIDE_ROOK_X, Board::BLACK_START_ROW) => self.black_king_side = false,
+                _ => {}
+            }
+        }
+    }
+}
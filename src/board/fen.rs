@@ -0,0 +1,185 @@
+use crate::board::{Board, BOARD_SIZE, BOARD_WIDTH, Position};
+use crate::board::castling_rights::CastlingRights;
+use crate::board::piece::{Color, PieceCode, Type};
+use crate::errors::ErrorKind;
+
+/// Board and side to move in Forsyth-Edwards Notation, as written by chess GUIs and databases.
+impl Board {
+    /// Reads the placement, side to move, castling and en passant fields of `fen`.
+    /// The move counters may be left out, when given they are checked but not kept, see [`crate::game::Game::from_fen`].
+    pub fn from_fen(fen: &str) -> Result<(Board, Color), ErrorKind> {
+        let fields = fen_fields(fen)?;
+        let squares = parse_placement(fields[0])?;
+        let side_to_move = match fields[1] {
+            "w" => Color::White,
+            "b" => Color::Black,
+            _ => return Err(ErrorKind::InvalidFenSideToMove),
+        };
+        let castling_rights = parse_castling(fields[2], &squares)?;
+        let en_passant = parse_en_passant(fields[3], &squares, &side_to_move)?;
+        parse_clocks(&fields)?;
+        let board = Board::from_parts(squares, castling_rights, en_passant);
+        let opponent = side_to_move.opposite();
+        let opponent_king = board.find_king(&opponent).ok_or(ErrorKind::InvalidFenKingCount)?;
+        if board.is_square_attacked(&opponent_king, side_to_move) {
+            return Err(ErrorKind::InvalidFenOpponentInCheck)
+        }
+        return Ok((board, side_to_move))
+    }
+
+    /// First four fields of the FEN record of this board with `side_to_move` to move,
+    /// the move counters are only known to [`crate::game::Game::to_fen`].
+    pub fn to_fen(&self, side_to_move: &Color) -> String {
+        let mut placement = String::new();
+        for y in (0..BOARD_WIDTH).rev() {
+            let mut empty = 0;
+            for x in 0..BOARD_WIDTH {
+                let code = self.code_at(&Position {x, y});
+                match (code.piece_type(), code.color()) {
+                    (Some(piece_type), Some(color)) => {
+                        if empty > 0 {
+                            placement.push_str(&empty.to_string());
+                            empty = 0;
+                        }
+                        placement.push(fen_letter(piece_type, color));
+                    }
+                    _ => empty += 1,
+                }
+            }
+            if empty > 0 {
+                placement.push_str(&empty.to_string());
+            }
+            if y > 0 {
+                placement.push('/');
+            }
+        }
+
+        let side = match side_to_move {
+            Color::White => "w",
+            Color::Black => "b",
+        };
+        let rights = self.castling_rights();
+        let castling: String = [(rights.white_king_side, 'K'), (rights.white_queen_side, 'Q'),
+                                (rights.black_king_side, 'k'), (rights.black_queen_side, 'q')]
+            .into_iter()
+            .filter_map(|(right, letter)| if right { Some(letter) } else { None })
+            .collect();
+        let en_passant = self.en_passant_target().map_or("-".to_string(), |pos| pos.to_string());
+        return format!("{} {} {} {}", placement, side,
+                       if castling.is_empty() { "-" } else { &castling }, en_passant)
+    }
+}
+
+/// Halfmove clock and fullmove number of `fen`, 0 and 1 when they are left out.
+pub(crate) fn fen_clocks(fen: &str) -> Result<(u32, u32), ErrorKind> {
+    return parse_clocks(&fen_fields(fen)?)
+}
+
+fn fen_fields(fen: &str) -> Result<Vec<&str>, ErrorKind> {
+    let fields: Vec<&str> = fen.split_whitespace().collect();
+    return match fields.len() {
+        4 | 6 => Ok(fields),
+        _ => Err(ErrorKind::InvalidFenFieldCount),
+    }
+}
+
+fn parse_placement(placement: &str) -> Result<[PieceCode; BOARD_SIZE], ErrorKind> {
+    let ranks: Vec<&str> = placement.split('/').collect();
+    if ranks.len() != BOARD_WIDTH {
+        return Err(ErrorKind::InvalidFenPlacement)
+    }
+
+    let mut squares = [PieceCode::EMPTY; BOARD_SIZE];
+    for (row, rank) in ranks.into_iter().enumerate() {
+        let y = BOARD_WIDTH - 1 - row;
+        let mut x = 0;
+        for symbol in rank.chars() {
+            if let Some(empty) = symbol.to_digit(10).filter(|empty| (1..=8).contains(empty)) {
+                x += empty as usize;
+                continue;
+            }
+            let piece_type = Type::from_letter(symbol).ok_or(ErrorKind::InvalidFenPlacement)?;
+            if x >= BOARD_WIDTH || (piece_type == Type::Pawn && (y == 0 || y == BOARD_WIDTH - 1)) {
+                return Err(ErrorKind::InvalidFenPlacement)
+            }
+            let color = if symbol.is_ascii_uppercase() { Color::White } else { Color::Black };
+            squares[y * BOARD_WIDTH + x] = PieceCode::new(piece_type, color);
+            x += 1;
+        }
+        if x != BOARD_WIDTH {
+            return Err(ErrorKind::InvalidFenPlacement)
+        }
+    }
+
+    for color in [Color::White, Color::Black] {
+        let king = PieceCode::new(Type::KING, color);
+        if squares.iter().filter(|code| **code == king).count() != 1 {
+            return Err(ErrorKind::InvalidFenKingCount)
+        }
+    }
+    return Ok(squares)
+}
+
+fn parse_castling(castling: &str, squares: &[PieceCode; BOARD_SIZE]) -> Result<CastlingRights, ErrorKind> {
+    let mut rights = CastlingRights::default();
+    if castling == "-" {
+        return Ok(rights)
+    }
+
+    for letter in castling.chars() {
+        let (right, color, rook_x) = match letter {
+            'K' => (&mut rights.white_king_side, Color::White, Board::KING_SIDE_ROOK_X),
+            'Q' => (&mut rights.white_queen_side, Color::White, Board::QUEEN_SIDE_ROOK_X),
+            'k' => (&mut rights.black_king_side, Color::Black, Board::KING_SIDE_ROOK_X),
+            'q' => (&mut rights.black_queen_side, Color::Black, Board::QUEEN_SIDE_ROOK_X),
+            _ => return Err(ErrorKind::InvalidFenCastling),
+        };
+        let row = Board::start_row(&color) * BOARD_WIDTH;
+        let has_pieces = squares[row + Board::KING_START_X] == PieceCode::new(Type::KING, color)
+            && squares[row + rook_x] == PieceCode::new(Type::Rook, color);
+        if *right || !has_pieces {
+            return Err(ErrorKind::InvalidFenCastling)
+        }
+        *right = true;
+    }
+    return Ok(rights)
+}
+
+fn parse_en_passant(en_passant: &str, squares: &[PieceCode; BOARD_SIZE], side_to_move: &Color) -> Result<Option<Position>, ErrorKind> {
+    if en_passant == "-" {
+        return Ok(None)
+    }
+
+    let target: Position = en_passant.parse().map_err(|_| ErrorKind::InvalidFenEnPassant)?;
+    let pawn_color = side_to_move.opposite();
+    let forward = Board::forward(&pawn_color);
+    let skipped_row = (Board::start_row(&pawn_color) as isize + 2 * forward) as usize;
+    if target.y != skipped_row {
+        return Err(ErrorKind::InvalidFenEnPassant)
+    }
+    let pawn = target.delta(0, forward);
+    let start = target.delta(0, -forward);
+    let is_double_step = squares[pawn.as_board_index()] == PieceCode::new(Type::Pawn, pawn_color)
+        && squares[target.as_board_index()].is_empty()
+        && squares[start.as_board_index()].is_empty();
+    return if is_double_step { Ok(Some(target)) } else { Err(ErrorKind::InvalidFenEnPassant) }
+}
+
+fn parse_clocks(fields: &[&str]) -> Result<(u32, u32), ErrorKind> {
+    if fields.len() < 6 {
+        return Ok((0, 1))
+    }
+    let halfmove_clock = fields[4].parse::<u32>().map_err(|_| ErrorKind::InvalidFenClock)?;
+    let fullmove_number = fields[5].parse::<u32>().map_err(|_| ErrorKind::InvalidFenClock)?;
+    if fullmove_number == 0 {
+        return Err(ErrorKind::InvalidFenClock)
+    }
+    return Ok((halfmove_clock, fullmove_number))
+}
+
+fn fen_letter(piece_type: Type, color: Color) -> char {
+    return match color {
+        Color::White => piece_type.letter(),
+        Color::Black => piece_type.letter().to_ascii_lowercase(),
+    }
+}
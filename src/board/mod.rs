@@ -1,18 +1,43 @@
 use std::{fmt};
 use std::fmt::{Formatter};
+use std::str::FromStr;
 use crate::errors::ErrorKind;
-use crate::board::piece::{Type, PieceState, PieceMoved, Color};
+use crate::board::piece::{Type, PieceCode, PieceState, PieceMoved, Color};
 use crate::board::piece::Type::{Bishop, KING, Knight, Pawn, Queen, Rook};
+use crate::moves::{Move, MoveFlag};
+use crate::board::castling_rights::CastlingRights;
+use crate::board::undo::Undo;
+use crate::board::bitboard::BitIter;
 
 pub mod piece;
+pub mod bitboard;
 pub mod board_display;
+pub mod castling_rights;
+pub mod fen;
+pub mod representation;
+pub mod undo;
+pub(crate) mod zobrist;
+
+#[cfg(test)]
+mod tests;
 
 pub const BOARD_WIDTH: usize = 8;
 pub const BOARD_SIZE: usize = BOARD_WIDTH * BOARD_WIDTH;
 
 
+/// Pieces as one byte per square, with castling rights and the en passant square
+/// kept for the whole board instead of for each piece.
+#[derive(Clone, PartialEq)]
 pub struct Board {
-    board: [Field; BOARD_SIZE]
+    squares: [PieceCode; BOARD_SIZE],
+    castling_rights: CastlingRights,
+    /// Index of the square skipped by the pawn which double-stepped in the last turn.
+    en_passant: Option<u8>,
+    /// Zobrist key of everything but the side to move, kept up to date by [`Board::make_move`].
+    key: u64,
+    /// Squares taken by each color, bit `y * 8 + x` standing for `Position {x, y}`,
+    /// so that attacks can be looked up in the tables of [`bitboard::attack_tables`].
+    colors: [u64; 2],
 }
 
 impl Board {
@@ -22,36 +47,286 @@ impl Board {
     pub const BLACK_START_ROW: usize = 7;
     const BLACK_PAWN_ROW: usize = 6;
     pub const BLACK_FORWARD: isize = -1;
+    pub const KING_START_X: usize = 4;
+    pub const KING_SIDE_ROOK_X: usize = 7;
+    pub const QUEEN_SIDE_ROOK_X: usize = 0;
 
     #[inline]
-    pub(crate) fn value_at(&self, pos: &Position) -> &Field {
-        return &self.board[pos.y*BOARD_WIDTH + pos.x]
+    pub fn forward(color: &Color) -> isize {
+        return match color {
+            Color::White => Board::WHITE_FORWARD,
+            Color::Black => Board::BLACK_FORWARD,
+        }
     }
-    
+
     #[inline]
-    pub(crate) fn is_color(&self, pos: &Position, color: &Color) -> bool {
-        return match self.value_at(pos) {
-            Field::Empty => false,
-            Field::Piece(state) => state.color == *color
+    pub fn start_row(color: &Color) -> usize {
+        return match color {
+            Color::White => Board::WHITE_START_ROW,
+            Color::Black => Board::BLACK_START_ROW,
+        }
+    }
+
+    #[inline]
+    pub fn pawn_row(color: &Color) -> usize {
+        return match color {
+            Color::White => Board::WHITE_PAWN_ROW,
+            Color::Black => Board::BLACK_PAWN_ROW,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn code_at(&self, pos: &Position) -> PieceCode {
+        return self.squares[pos.as_board_index()]
+    }
+
+    /// Field with the piece standing on `pos`, see [`Board::piece_at`].
+    #[inline]
+    pub(crate) fn value_at(&self, pos: &Position) -> Field {
+        return match self.piece_at(pos) {
+            Some(state) => Field::Piece(state),
+            None => Field::Empty,
         }
     }
 
+    #[inline]
+    pub(crate) fn is_color(&self, pos: &Position, color: &Color) -> bool {
+        return self.code_at(pos).is_color(color)
+    }
+
     #[inline]
     pub(crate) fn is_empty(&self, pos: &Position) -> bool {
-        return self.value_at(pos) == &Field::Empty
+        return self.code_at(pos).is_empty()
     }
 
+    /// Piece standing on `pos`, its moved state is worked out by [`Board::derive_moved`].
     #[inline]
-    pub(crate) fn is_empty_or_color(&self, pos: &Position, color: &Color) -> bool {
-        return match self.value_at(pos) {
-            Field::Empty => true,
-            Field::Piece(state) => state.color == *color
+    pub(crate) fn piece_at(&self, pos: &Position) -> Option<PieceState> {
+        let code = self.code_at(pos);
+        let moved = Board::derive_moved(pos, code.piece_type()?, &code.color()?,
+                                        &self.castling_rights, self.en_passant_target());
+        return code.with_moved(moved)
+    }
+
+    /// Moved state of a piece as far as it matters to the rules: `No` for pawns on their start row
+    /// and for kings and rooks which can still castle, `LastTurn` for a pawn which can be taken
+    /// en passant and `Yes` otherwise.
+    pub(crate) fn derive_moved(pos: &Position, piece_type: Type, color: &Color,
+                               castling_rights: &CastlingRights, en_passant: Option<Position>) -> PieceMoved {
+        let start_row = Board::start_row(color);
+        let is_unmoved = match piece_type {
+            Pawn => pos.y == Board::pawn_row(color),
+            KING => pos.y == start_row && pos.x == Board::KING_START_X
+                && (castling_rights.king_side(color) || castling_rights.queen_side(color)),
+            Rook => pos.y == start_row && (
+                (pos.x == Board::KING_SIDE_ROOK_X && castling_rights.king_side(color))
+                    || (pos.x == Board::QUEEN_SIDE_ROOK_X && castling_rights.queen_side(color))),
+            _ => false,
+        };
+        let just_double_stepped = piece_type == Pawn && en_passant
+            .map(|target| target.delta(0, Board::forward(color)) == *pos)
+            .unwrap_or(false);
+
+        return if is_unmoved {
+            PieceMoved::No
+        } else if just_double_stepped {
+            PieceMoved::LastTurn
+        } else {
+            PieceMoved::Yes
         }
     }
 
-    pub(crate) fn move_piece(&mut self, from: &Position, to: &Position) {
-        self.board[to.as_board_index()] = Field::Empty;
-        self.board.swap(from.as_board_index(), to.as_board_index());
+    pub fn apply_move(&mut self, mv: &Move) {
+        self.make_move(mv);
+    }
+
+    /// Plays `mv` and returns what is needed to take it back with [`Board::unmake_move`].
+    pub fn make_move(&mut self, mv: &Move) -> Undo {
+        let captured_pos = Move::captured_pos(&mv.from, &mv.to, mv.flag);
+        let undo = Undo {
+            moved_piece: self.code_at(&mv.from),
+            captured_piece: self.code_at(&captured_pos),
+            captured_pos,
+            castling_rights: self.castling_rights,
+            en_passant: self.en_passant,
+            key: self.key,
+        };
+        let en_passant_key_before = self.en_passant_key();
+
+        self.squares[undo.captured_pos.as_board_index()] = PieceCode::EMPTY;
+        self.squares[mv.from.as_board_index()] = PieceCode::EMPTY;
+        self.squares[mv.to.as_board_index()] = match mv.promotion {
+            Some(promotion) => PieceCode::new(promotion, mv.piece.color),
+            None => undo.moved_piece,
+        };
+        if let Some(captured_color) = undo.captured_piece.color() {
+            self.colors[captured_color.index()] &= !(1 << undo.captured_pos.as_board_index());
+        }
+        self.move_color_bit(&mv.piece.color, mv.from.as_board_index(), mv.to.as_board_index());
+        if mv.is_castling() {
+            let (rook_from, rook_to) = Board::castling_rook_positions(&mv.from, &mv.to);
+            self.squares.swap(rook_from.as_board_index(), rook_to.as_board_index());
+            self.move_color_bit(&mv.piece.color, rook_from.as_board_index(), rook_to.as_board_index());
+        }
+
+        self.en_passant = match mv.flag {
+            MoveFlag::DoublePawnPush => Some(((mv.from.as_board_index() + mv.to.as_board_index()) / 2) as u8),
+            _ => None,
+        };
+        self.castling_rights.update(mv);
+        self.update_key(mv, &undo, en_passant_key_before);
+        return undo
+    }
+
+    /// Updates the key with the pieces `mv` moved, took and promoted, and the castling rights
+    /// and en passant square which changed with it.
+    fn update_key(&mut self, mv: &Move, undo: &Undo, en_passant_key_before: u64) {
+        let mut key = self.key;
+        key ^= zobrist::piece_key(mv.piece.piece_type, mv.piece.color, mv.from.as_board_index());
+        key ^= zobrist::piece_key(mv.promotion.unwrap_or(mv.piece.piece_type), mv.piece.color, mv.to.as_board_index());
+        if let (Some(piece_type), Some(color)) = (undo.captured_piece.piece_type(), undo.captured_piece.color()) {
+            key ^= zobrist::piece_key(piece_type, color, undo.captured_pos.as_board_index());
+        }
+        if mv.is_castling() {
+            let (rook_from, rook_to) = Board::castling_rook_positions(&mv.from, &mv.to);
+            key ^= zobrist::piece_key(Rook, mv.piece.color, rook_from.as_board_index());
+            key ^= zobrist::piece_key(Rook, mv.piece.color, rook_to.as_board_index());
+        }
+
+        key ^= zobrist::castling_key(&undo.castling_rights) ^ zobrist::castling_key(&self.castling_rights);
+        key ^= en_passant_key_before ^ self.en_passant_key();
+        self.key = key;
+    }
+
+    /// Takes back `mv` which was the last move made with [`Board::make_move`].
+    pub fn unmake_move(&mut self, mv: &Move, undo: Undo) {
+        if mv.is_castling() {
+            let (rook_from, rook_to) = Board::castling_rook_positions(&mv.from, &mv.to);
+            self.squares.swap(rook_from.as_board_index(), rook_to.as_board_index());
+            self.move_color_bit(&mv.piece.color, rook_to.as_board_index(), rook_from.as_board_index());
+        }
+        self.squares[mv.to.as_board_index()] = PieceCode::EMPTY;
+        self.squares[mv.from.as_board_index()] = undo.moved_piece;
+        self.squares[undo.captured_pos.as_board_index()] = undo.captured_piece;
+        self.move_color_bit(&mv.piece.color, mv.to.as_board_index(), mv.from.as_board_index());
+        if let Some(captured_color) = undo.captured_piece.color() {
+            self.colors[captured_color.index()] |= 1 << undo.captured_pos.as_board_index();
+        }
+        self.castling_rights = undo.castling_rights;
+        self.en_passant = undo.en_passant;
+        self.key = undo.key;
+    }
+
+    #[inline]
+    fn move_color_bit(&mut self, color: &Color, from: usize, to: usize) {
+        self.colors[color.index()] = self.colors[color.index()] & !(1 << from) | 1 << to;
+    }
+
+    /// Moves the piece standing at `from`, a pawn reaching the last row becomes a queen.
+    pub fn move_piece(&mut self, from: &Position, to: &Position) {
+        let promotion = if self.is_promotion(from, to) { Some(Queen) } else { None };
+        if let Some(mv) = Move::infer(self, from, to, promotion) {
+            self.apply_move(&mv);
+        }
+    }
+
+    /// Same as [`Board::move_piece`] for a pawn reaching the last row, but the pawn is
+    /// replaced with `promotion` instead of a queen.
+    pub fn move_piece_with_promotion(&mut self, from: &Position, to: &Position, promotion: Type) -> Result<(), ErrorKind> {
+        if !Type::PROMOTIONS.contains(&promotion) || !self.is_promotion(from, to) {
+            return Err(ErrorKind::InvalidPromotion)
+        }
+
+        if let Some(mv) = Move::infer(self, from, to, Some(promotion)) {
+            self.apply_move(&mv);
+        }
+        return Ok(())
+    }
+
+    pub fn is_promotion(&self, from: &Position, to: &Position) -> bool {
+        let code = self.code_at(from);
+        return match code.color() {
+            Some(color) => code.piece_type() == Some(Pawn) && to.y == Board::start_row(&color.opposite()),
+            None => false,
+        }
+    }
+
+    fn castling_rook_positions(king_from: &Position, king_to: &Position) -> (Position, Position) {
+        let (rook_from_x, rook_to_x) = if king_to.x > king_from.x {
+            (Board::KING_SIDE_ROOK_X, king_to.x - 1)
+        } else {
+            (Board::QUEEN_SIDE_ROOK_X, king_to.x + 1)
+        };
+        return (Position {x: rook_from_x, y: king_from.y}, Position {x: rook_to_x, y: king_from.y})
+    }
+
+    /// Positions of the pieces of `color`, row by row starting with `y = 0`.
+    pub(crate) fn positions_of<'a>(&'a self, color: &'a Color) -> impl Iterator<Item = Position> + 'a {
+        return BitIter(self.color_set(color)).map(Position::from_board_index)
+    }
+
+    /// Squares taken by pieces of `color`, bit `y * 8 + x` standing for `Position {x, y}`.
+    #[inline]
+    pub fn color_set(&self, color: &Color) -> u64 {
+        return self.colors[color.index()]
+    }
+
+    #[inline]
+    pub fn occupied(&self) -> u64 {
+        return self.colors[0] | self.colors[1]
+    }
+
+    #[inline]
+    pub fn castling_rights(&self) -> CastlingRights {
+        return self.castling_rights
+    }
+
+    /// Square skipped by the pawn which double-stepped in the last turn, whether or not
+    /// any enemy pawn can take it en passant.
+    #[inline]
+    pub fn en_passant_target(&self) -> Option<Position> {
+        return self.en_passant.map(|square| Position::from_board_index(square as usize))
+    }
+
+    /// Zobrist key of the position with `side_to_move` to move, laid out like Polyglot keys:
+    /// the en passant file counts only when a pawn of the side to move stands beside
+    /// the pawn which double-stepped.
+    #[inline]
+    pub fn zobrist_key(&self, side_to_move: &Color) -> u64 {
+        return self.key ^ zobrist::turn_key(side_to_move)
+    }
+
+    /// Key of everything but the side to move, worked out from scratch.
+    pub(crate) fn compute_key(&self) -> u64 {
+        let mut key = zobrist::castling_key(&self.castling_rights) ^ self.en_passant_key();
+        for (index, code) in self.squares.iter().enumerate() {
+            if let (Some(piece_type), Some(color)) = (code.piece_type(), code.color()) {
+                key ^= zobrist::piece_key(piece_type, color, index);
+            }
+        }
+        return key
+    }
+
+    /// En passant part of the key, set only when an enemy pawn stands beside the pawn which double-stepped.
+    fn en_passant_key(&self) -> u64 {
+        let target = match self.en_passant_target() {
+            None => return 0,
+            Some(target) => target,
+        };
+        let pawn_color = if target.y < BOARD_WIDTH / 2 { Color::White } else { Color::Black };
+        let pawn_pos = target.delta(0, Board::forward(&pawn_color));
+        let capturer = PieceCode::new(Pawn, pawn_color.opposite());
+        let capturer_beside = [-1, 1].into_iter()
+            .filter_map(|delta_x| pawn_pos.delta_if_valid(delta_x, 0).ok())
+            .any(|pos| self.code_at(&pos) == capturer);
+        return if capturer_beside { zobrist::en_passant_key(target.x) } else { 0 }
+    }
+
+    pub(crate) fn find_king(&self, color: &Color) -> Option<Position> {
+        let king = PieceCode::new(KING, *color);
+        return BitIter(self.color_set(color))
+            .find(|square| self.squares[*square] == king)
+            .map(Position::from_board_index)
     }
 }
 
@@ -59,23 +334,67 @@ impl Board {
     pub fn new() -> Board {
         let mut board = [Field::Empty; BOARD_SIZE];
         Board::init_white_row(&mut board, Board::WHITE_START_ROW,
-                              [Knight, Rook, Bishop, KING, Queen, Bishop, Rook, Knight]);
+                              [Rook, Knight, Bishop, Queen, KING, Bishop, Knight, Rook]);
         Board::init_white_row(&mut board, Board::WHITE_PAWN_ROW,
                               [Pawn, Pawn, Pawn, Pawn, Pawn, Pawn, Pawn, Pawn,]);
         Board::init_black_row(&mut board, Board::BLACK_PAWN_ROW,
                               [Pawn, Pawn, Pawn, Pawn, Pawn, Pawn, Pawn, Pawn,]);
         Board::init_black_row(&mut board, Board::BLACK_START_ROW,
-                              [Knight, Rook, Bishop, Queen, KING, Bishop, Rook, Knight]);
+                              [Rook, Knight, Bishop, Queen, KING, Bishop, Knight, Rook]);
 
-        return Board{ board }
+        return Board::from_fields(&board)
     }
 
+    /// Board with the given pieces. Kings and rooks which have not moved give the castling rights
+    /// and a pawn which moved in the last turn gives the en passant square.
     pub fn new_from(fields: Vec<(Field, Position)>) -> Board {
         let mut board = [Field::Empty; BOARD_SIZE];
         for field in fields {
             board[field.1.as_board_index()] = field.0;
         }
-        return Board{board};
+        return Board::from_fields(&board);
+    }
+
+    /// Board with the given pieces and the state which is not visible from the pieces themselves.
+    pub fn from_parts(squares: [PieceCode; BOARD_SIZE], castling_rights: CastlingRights, en_passant: Option<Position>) -> Board {
+        let mut colors = [0; 2];
+        for (square, code) in squares.iter().enumerate() {
+            if let Some(color) = code.color() {
+                colors[color.index()] |= 1 << square;
+            }
+        }
+        let mut board = Board {
+            squares,
+            castling_rights,
+            en_passant: en_passant.map(|pos| pos.as_board_index() as u8),
+            key: 0,
+            colors,
+        };
+        board.key = board.compute_key();
+        return board
+    }
+
+    fn from_fields(fields: &[Field; BOARD_SIZE]) -> Board {
+        let is_unmoved = |x: usize, y: usize, piece_type: Type, color: Color| matches!(
+            fields[y * BOARD_WIDTH + x],
+            Field::Piece(state) if state.piece_type == piece_type && state.color == color && state.moved == PieceMoved::No);
+        let can_castle = |color: Color, rook_x: usize| {
+            let row = Board::start_row(&color);
+            return is_unmoved(Board::KING_START_X, row, KING, color) && is_unmoved(rook_x, row, Rook, color)
+        };
+        let castling_rights = CastlingRights {
+            white_king_side: can_castle(Color::White, Board::KING_SIDE_ROOK_X),
+            white_queen_side: can_castle(Color::White, Board::QUEEN_SIDE_ROOK_X),
+            black_king_side: can_castle(Color::Black, Board::KING_SIDE_ROOK_X),
+            black_queen_side: can_castle(Color::Black, Board::QUEEN_SIDE_ROOK_X),
+        };
+        let en_passant = fields.iter().enumerate().find_map(|(index, field)| match field {
+            Field::Piece(state) if state.piece_type == Pawn && state.moved == PieceMoved::LastTurn =>
+                Some(Position::from_board_index(index).delta(0, -Board::forward(&state.color))),
+            _ => None,
+        });
+
+        return Board::from_parts(fields.map(|field| PieceCode::from(&field)), castling_rights, en_passant)
     }
 
     fn init_black_row(board: &mut[Field; BOARD_SIZE], row: usize, types: [Type; 8]) {
@@ -93,6 +412,12 @@ impl Board {
     }
 }
 
+impl Default for Board {
+    fn default() -> Self {
+        return Board::new()
+    }
+}
+
 #[derive(Copy, Clone, PartialEq)]
 pub enum Field {
     Piece(PieceState),
@@ -114,6 +439,16 @@ impl Default for Field {
     }
 }
 
+impl From<&Field> for PieceCode {
+    #[inline]
+    fn from(field: &Field) -> Self {
+        return match field {
+            Field::Piece(state) => PieceCode::from(*state),
+            Field::Empty => PieceCode::EMPTY,
+        }
+    }
+}
+
 impl Field {
 
     #[inline]
@@ -135,7 +470,7 @@ impl Field {
     }
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct Position {
     pub x: usize,
     pub y: usize,
@@ -162,18 +497,44 @@ impl Position {
     }
 
     #[inline]
-    pub(crate) fn delta_x(&self, x: isize) -> Position {
-        return Position {x: (self.x as isize + x) as usize, y: self.y}
+    pub(crate) fn as_board_index(&self) -> usize {
+        return self.y * BOARD_WIDTH + self.x
     }
 
     #[inline]
-    pub(crate) fn delta_y(&self, y: isize) -> Position {
-        return Position {x: self.x, y: (self.y as isize + y) as usize }
+    pub(crate) fn from_board_index(index: usize) -> Position {
+        return Position {x: index % BOARD_WIDTH, y: index / BOARD_WIDTH}
     }
 
+    /// File letter, `'a'` for `x == 0` which is the queen side of [`Board::new`].
     #[inline]
-    fn as_board_index(&self) -> usize {
-        return self.y * BOARD_WIDTH + self.x
+    pub fn file(&self) -> char {
+        return (b'a' + self.x as u8) as char
     }
 
+    /// Rank number from 1 to 8, 1 for `y == 0` which is the first row of the white pieces.
+    #[inline]
+    pub fn rank(&self) -> u8 {
+        return self.y as u8 + 1
+    }
+}
+
+/// Algebraic square name like "e4".
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}{}", self.file(), self.rank())
+    }
+}
+
+impl FromStr for Position {
+    type Err = ErrorKind;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let mut chars = name.chars();
+        return match (chars.next(), chars.next(), chars.next()) {
+            (Some(file @ 'a'..='h'), Some(rank @ '1'..='8'), None) =>
+                Ok(Position {x: file as usize - 'a' as usize, y: rank as usize - '1' as usize}),
+            _ => Err(ErrorKind::InvalidSquareName),
+        }
+    }
 }
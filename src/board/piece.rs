@@ -1,6 +1,6 @@
 use std::fmt::{Display, Formatter};
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub struct PieceState {
     pub piece_type: Type,
     pub color: Color,
@@ -21,7 +21,7 @@ impl Display for PieceState {
             Type::KING => "K",
             Type::Pawn => "p"
         };
-        write!(f, "{}{}{}", color_str, piece_str, "\x1B[0m") // Reset color
+        write!(f, "{}{}\x1B[0m", color_str, piece_str) // Reset color
     }
 }
 
@@ -42,7 +42,7 @@ impl PieceState {
             (Type::Pawn, Color::White) => "♙",
             (Type::Pawn, Color::Black) => "♟︎"
         };
-        write!(f, "{}{}{}", color_str, piece_str, "\x1B[0m") // Reset color
+        write!(f, "{}{}\x1B[0m", color_str, piece_str) // Reset color
     }
 }
 
@@ -58,6 +58,33 @@ pub enum Type {
     Pawn,
 }
 
+impl Type {
+    pub const PROMOTIONS: [Type; 4] = [Type::Queen, Type::Rook, Type::Bishop, Type::Knight];
+    pub const ALL: [Type; 6] = [Type::Rook, Type::Knight, Type::Bishop, Type::Queen, Type::KING, Type::Pawn];
+
+    #[inline]
+    pub(crate) fn index(&self) -> usize {
+        return *self as usize
+    }
+
+    /// Upper case letter of the piece in FEN and algebraic notation.
+    pub fn letter(&self) -> char {
+        return match self {
+            Type::Rook => 'R',
+            Type::Knight => 'N',
+            Type::Bishop => 'B',
+            Type::Queen => 'Q',
+            Type::KING => 'K',
+            Type::Pawn => 'P',
+        }
+    }
+
+    /// Type of an upper or lower case letter of [`Type::letter`].
+    pub fn from_letter(letter: char) -> Option<Type> {
+        return Type::ALL.into_iter().find(|piece_type| piece_type.letter() == letter.to_ascii_uppercase())
+    }
+}
+
 #[derive(Copy, Clone, PartialEq)]
 #[derive(Debug)]
 pub enum Color {
@@ -66,6 +93,11 @@ pub enum Color {
 }
 
 impl Color {
+    #[inline]
+    pub(crate) fn index(&self) -> usize {
+        return *self as usize
+    }
+
     #[inline]
     pub(crate) fn opposite(&self) -> Color {
         return match self {
@@ -82,3 +114,70 @@ pub enum PieceMoved {
     LastTurn,
     No,
 }
+
+/// Piece type and color packed into one byte, `0` stands for an empty square.
+/// The low three bits hold [`Type::index`] plus one and the fourth bit is set for black pieces.
+/// Unlike [`PieceState`] it does not know whether the piece has moved, boards keep that
+/// as castling rights and en passant square instead.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct PieceCode(u8);
+
+impl PieceCode {
+    pub const EMPTY: PieceCode = PieceCode(0);
+    const TYPE_MASK: u8 = 0b0111;
+    const BLACK_BIT: u8 = 0b1000;
+
+    #[inline]
+    pub const fn new(piece_type: Type, color: Color) -> PieceCode {
+        let color_bit = match color {
+            Color::White => 0,
+            Color::Black => PieceCode::BLACK_BIT,
+        };
+        return PieceCode((piece_type as u8 + 1) | color_bit)
+    }
+
+    #[inline]
+    pub fn bits(&self) -> u8 {
+        return self.0
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        return self.0 == 0
+    }
+
+    #[inline]
+    pub fn piece_type(&self) -> Option<Type> {
+        return match self.0 & PieceCode::TYPE_MASK {
+            0 => None,
+            type_bits => Some(Type::ALL[type_bits as usize - 1]),
+        }
+    }
+
+    #[inline]
+    pub fn color(&self) -> Option<Color> {
+        return match (self.is_empty(), self.0 & PieceCode::BLACK_BIT) {
+            (true, _) => None,
+            (false, 0) => Some(Color::White),
+            _ => Some(Color::Black),
+        }
+    }
+
+    #[inline]
+    pub fn is_color(&self, color: &Color) -> bool {
+        return self.color() == Some(*color)
+    }
+
+    /// Piece with the moved state it is known to have, `None` for an empty square.
+    #[inline]
+    pub fn with_moved(&self, moved: PieceMoved) -> Option<PieceState> {
+        return Some(PieceState {piece_type: self.piece_type()?, color: self.color()?, moved})
+    }
+}
+
+impl From<PieceState> for PieceCode {
+    #[inline]
+    fn from(state: PieceState) -> Self {
+        return PieceCode::new(state.piece_type, state.color)
+    }
+}
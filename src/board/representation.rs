@@ -0,0 +1,213 @@
+use crate::board::{Board, Field, Position};
+use crate::board::bitboard::{BitBoard, BitIter};
+use crate::board::castling_rights::CastlingRights;
+use crate::board::piece::{Color, PieceState, Type};
+use crate::board::undo::Undo;
+use crate::moves::{Move, MoveList, MoveStage};
+
+/// What the move generator needs to know about a position and how it plays moves on it,
+/// so that it works the same on every way of storing the pieces.
+pub trait BoardRepresentation: Clone {
+    /// Whatever [`BoardRepresentation::make_move`] needs to take a move back.
+    type Undo;
+
+    fn piece_at(&self, pos: &Position) -> Option<PieceState>;
+
+    fn is_empty(&self, pos: &Position) -> bool;
+
+    fn is_color(&self, pos: &Position, color: &Color) -> bool;
+
+    /// Squares with pieces of `color`, bit `y * 8 + x` standing for `Position {x, y}`.
+    fn color_set(&self, color: &Color) -> u64;
+
+    fn castling_rights(&self) -> CastlingRights;
+
+    /// Square skipped by the pawn which double-stepped in the last turn.
+    fn en_passant_target(&self) -> Option<Position>;
+
+    /// Whether any piece of color `by` attacks `pos`, no matter what stands there.
+    fn is_square_attacked(&self, pos: &Position, by: Color) -> bool;
+
+    /// Legal moves of every piece of `color`, only those of `stage` when one is given,
+    /// see [`crate::moves::get_all_legal_moves`].
+    fn legal_moves(&self, color: &Color, stage: Option<MoveStage>, moves: &mut MoveList);
+
+    /// See [`Board::zobrist_key`].
+    fn zobrist_key(&self, side_to_move: &Color) -> u64;
+
+    fn make_move(&mut self, mv: &Move) -> Self::Undo;
+
+    /// Takes back `mv` which was the last move made with [`BoardRepresentation::make_move`].
+    fn unmake_move(&mut self, mv: &Move, undo: Self::Undo);
+
+    #[inline]
+    fn value_at(&self, pos: &Position) -> Field {
+        return match self.piece_at(pos) {
+            Some(state) => Field::Piece(state),
+            None => Field::Empty,
+        }
+    }
+
+    #[inline]
+    fn is_empty_or_color(&self, pos: &Position, color: &Color) -> bool {
+        return self.is_empty(pos) || self.is_color(pos, color)
+    }
+
+    /// Positions of the pieces of `color`, row by row starting with `y = 0`.
+    fn positions_of<'a>(&'a self, color: &'a Color) -> impl Iterator<Item = Position> + 'a {
+        return BitIter(self.color_set(color)).map(Position::from_board_index)
+    }
+
+    fn find_king(&self, color: &Color) -> Option<Position> {
+        return self.positions_of(color)
+            .find(|pos| matches!(self.piece_at(pos), Some(state) if state.piece_type == Type::KING))
+    }
+
+    /// Moves the piece standing at `from`, a pawn reaching the last row becomes a queen.
+    fn move_piece(&mut self, from: &Position, to: &Position) {
+        let is_promotion = matches!(self.piece_at(from),
+            Some(state) if state.piece_type == Type::Pawn && to.y == Board::start_row(&state.color.opposite()));
+        let promotion = if is_promotion { Some(Type::Queen) } else { None };
+        if let Some(mv) = Move::infer(self, from, to, promotion) {
+            self.make_move(&mv);
+        }
+    }
+}
+
+impl BoardRepresentation for Board {
+    type Undo = Undo;
+
+    #[inline]
+    fn piece_at(&self, pos: &Position) -> Option<PieceState> {
+        return Board::piece_at(self, pos)
+    }
+
+    #[inline]
+    fn is_empty(&self, pos: &Position) -> bool {
+        return Board::is_empty(self, pos)
+    }
+
+    #[inline]
+    fn is_color(&self, pos: &Position, color: &Color) -> bool {
+        return Board::is_color(self, pos, color)
+    }
+
+    #[inline]
+    fn color_set(&self, color: &Color) -> u64 {
+        return Board::color_set(self, color)
+    }
+
+    #[inline]
+    fn is_empty_or_color(&self, pos: &Position, color: &Color) -> bool {
+        let code = self.code_at(pos);
+        return code.is_empty() || code.is_color(color)
+    }
+
+    #[inline]
+    fn castling_rights(&self) -> CastlingRights {
+        return Board::castling_rights(self)
+    }
+
+    #[inline]
+    fn en_passant_target(&self) -> Option<Position> {
+        return Board::en_passant_target(self)
+    }
+
+    #[inline]
+    fn is_square_attacked(&self, pos: &Position, by: Color) -> bool {
+        return Board::is_square_attacked(self, pos, by)
+    }
+
+    /// Generated on a [`BitBoard`] copy, which knows the pinned pieces and the squares answering a check.
+    #[inline]
+    fn legal_moves(&self, color: &Color, stage: Option<MoveStage>, moves: &mut MoveList) {
+        BitBoard::from_board(self).get_legal_stage_moves(color, stage, moves);
+    }
+
+    #[inline]
+    fn zobrist_key(&self, side_to_move: &Color) -> u64 {
+        return Board::zobrist_key(self, side_to_move)
+    }
+
+    #[inline]
+    fn make_move(&mut self, mv: &Move) -> Undo {
+        return Board::make_move(self, mv)
+    }
+
+    #[inline]
+    fn unmake_move(&mut self, mv: &Move, undo: Undo) {
+        Board::unmake_move(self, mv, undo);
+    }
+
+    #[inline]
+    fn find_king(&self, color: &Color) -> Option<Position> {
+        return Board::find_king(self, color)
+    }
+}
+
+impl BoardRepresentation for BitBoard {
+    /// Whole board before the move, as it is only a few words.
+    type Undo = BitBoard;
+
+    #[inline]
+    fn piece_at(&self, pos: &Position) -> Option<PieceState> {
+        return BitBoard::piece_at(self, pos)
+    }
+
+    #[inline]
+    fn is_empty(&self, pos: &Position) -> bool {
+        return BitBoard::is_empty(self, pos)
+    }
+
+    #[inline]
+    fn is_color(&self, pos: &Position, color: &Color) -> bool {
+        return BitBoard::is_color(self, pos, color)
+    }
+
+    #[inline]
+    fn color_set(&self, color: &Color) -> u64 {
+        return BitBoard::color_set(self, color)
+    }
+
+    #[inline]
+    fn castling_rights(&self) -> CastlingRights {
+        return BitBoard::castling_rights(self)
+    }
+
+    #[inline]
+    fn en_passant_target(&self) -> Option<Position> {
+        return BitBoard::en_passant_target(self)
+    }
+
+    #[inline]
+    fn is_square_attacked(&self, pos: &Position, by: Color) -> bool {
+        return BitBoard::is_square_attacked(self, pos.as_board_index(), &by)
+    }
+
+    #[inline]
+    fn legal_moves(&self, color: &Color, stage: Option<MoveStage>, moves: &mut MoveList) {
+        BitBoard::get_legal_stage_moves(self, color, stage, moves);
+    }
+
+    #[inline]
+    fn zobrist_key(&self, side_to_move: &Color) -> u64 {
+        return BitBoard::zobrist_key(self, side_to_move)
+    }
+
+    #[inline]
+    fn make_move(&mut self, mv: &Move) -> BitBoard {
+        let before = self.clone();
+        BitBoard::make_move(self, mv);
+        return before
+    }
+
+    #[inline]
+    fn unmake_move(&mut self, _mv: &Move, undo: BitBoard) {
+        *self = undo;
+    }
+
+    #[inline]
+    fn find_king(&self, color: &Color) -> Option<Position> {
+        return BitIter(self.pieces(Type::KING, color)).next().map(Position::from_board_index)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::board::{Board, Position};
+use crate::board::piece::Color::{Black, White};
+use crate::errors::ErrorKind;
+use crate::game::Game;
+use crate::moves::Move;
+
+const START: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+fn assert_error(fen: &str, expected: ErrorKind) {
+    assert_eq!(Some(expected), Board::from_fen(fen).err(), "{}", fen);
+}
+
+#[test]
+fn test_start_position() {
+    let (board, color) = Board::from_fen(START).unwrap();
+    assert!(board == Board::new());
+    assert_eq!(White, color);
+    assert_eq!(START, Game::new().to_fen());
+}
+
+#[test]
+fn test_round_trip() {
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+        "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b Kq e3",
+    ] {
+        let (board, color) = Board::from_fen(fen).unwrap();
+        assert_eq!(fen, board.to_fen(&color));
+    }
+}
+
+#[test]
+fn test_en_passant_and_castling_state() {
+    let (board, color) = Board::from_fen("rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b Kq e3 0 3").unwrap();
+    assert_eq!(Black, color);
+    assert_eq!(Some(Position {x: 4, y: 2}), board.en_passant_target());
+    assert!(board.castling_rights().white_king_side);
+    assert!(!board.castling_rights().white_queen_side);
+    assert!(!board.castling_rights().black_king_side);
+    assert!(board.castling_rights().black_queen_side);
+    assert!(Move::infer(&board, &Position {x: 3, y: 3}, &Position {x: 4, y: 2}, None).is_some());
+}
+
+#[test]
+fn test_game_keeps_move_counters() {
+    let fen = "rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2";
+    let game = Game::from_fen(fen).unwrap();
+    assert_eq!(1, game.halfmove_clock());
+    assert_eq!(2, game.fullmove_number());
+    assert_eq!(fen, game.to_fen());
+}
+
+#[test]
+fn test_malformed_fen() {
+    assert_error("", ErrorKind::InvalidFenFieldCount);
+    assert_error("8/8/8/8/8/8/8/8 w", ErrorKind::InvalidFenFieldCount);
+    assert_error("4k3/8/8/8/8/8/8/4K3 w - - 0", ErrorKind::InvalidFenFieldCount);
+    assert_error("4k3/8/8/8/8/8/4K3 w - -", ErrorKind::InvalidFenPlacement);
+    assert_error("4k3/8/8/8/8/8/8/4K4 w - -", ErrorKind::InvalidFenPlacement);
+    assert_error("4k3/8/8/8/8/8/8/4K2 w - -", ErrorKind::InvalidFenPlacement);
+    assert_error("4k3/8/8/8/8/8/8/4X3 w - -", ErrorKind::InvalidFenPlacement);
+    assert_error("4k3/8/8/8/8/8/8/8 w - -", ErrorKind::InvalidFenKingCount);
+    assert_error("4k3/8/8/8/8/8/8/3KK3 w - -", ErrorKind::InvalidFenKingCount);
+    assert_error("R3k3/8/8/8/8/8/8/4K3 w - - 0 1", ErrorKind::InvalidFenOpponentInCheck);
+    assert_error("4k3/8/8/8/8/8/8/r3K3 b - - 0 1", ErrorKind::InvalidFenOpponentInCheck);
+    assert_error("4k2P/8/8/8/8/8/8/4K3 w - -", ErrorKind::InvalidFenPlacement);
+    assert_error("4k3/8/8/8/8/8/8/4K3 x - -", ErrorKind::InvalidFenSideToMove);
+    assert_error("4k3/8/8/8/8/8/8/4K3 w K -", ErrorKind::InvalidFenCastling);
+    assert_error("4k3/8/8/8/8/8/8/4K2R w KK -", ErrorKind::InvalidFenCastling);
+    assert_error("4k3/8/8/8/8/8/8/4K2R w X -", ErrorKind::InvalidFenCastling);
+    assert_error("4k3/8/8/8/8/8/8/4K3 w - e9", ErrorKind::InvalidFenEnPassant);
+    assert_error("4k3/8/8/8/4P3/8/8/4K3 w - e3", ErrorKind::InvalidFenEnPassant);
+    assert_error("4k3/8/8/8/8/8/8/4K3 b - e3", ErrorKind::InvalidFenEnPassant);
+    assert_error("4k3/8/8/8/8/8/8/4K3 w - - x 1", ErrorKind::InvalidFenClock);
+    assert_error("4k3/8/8/8/8/8/8/4K3 w - - 0 0", ErrorKind::InvalidFenClock);
+}
@@ -0,0 +1,87 @@
+use crate::board::{Board, Field, Position};
+use crate::board::piece::Color::{Black, White};
+use crate::board::piece::{PieceMoved, PieceState};
+use crate::board::piece::Type::{KING, Knight, Pawn, Queen, Rook};
+use crate::moves;
+use crate::moves::{Move, MoveFlag, MoveList};
+
+fn assert_every_move_unmakes(board: &Board) {
+    let mut scratch_board = board.clone();
+    for color in [White, Black] {
+        for pos in board.positions_of(&color) {
+            let mut moves = MoveList::new();
+            moves::get_moves(board, &pos, &mut moves);
+            for mv in &moves {
+                let undo = scratch_board.make_move(mv);
+                assert!(scratch_board != *board);
+                scratch_board.unmake_move(mv, undo);
+                assert!(scratch_board == *board, "{:?} was not unmade", mv);
+            }
+        }
+    }
+}
+
+fn rich_board() -> Board {
+    let mut board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Rook), Position {x: 0, y: 0}),
+        (Field::new_white(Rook), Position {x: 7, y: 0}),
+        (Field::Piece(PieceState {piece_type: Pawn, color: White, moved: PieceMoved::Yes}), Position {x: 4, y: 4}),
+        (Field::Piece(PieceState {piece_type: Pawn, color: White, moved: PieceMoved::Yes}), Position {x: 1, y: 6}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+        (Field::new_black(Rook), Position {x: 7, y: 7}),
+        (Field::new_black(Knight), Position {x: 0, y: 7}),
+        (Field::new_black(Queen), Position {x: 2, y: 2}),
+        (Field::new_black(Pawn), Position {x: 3, y: 6}),
+    ]);
+    board.move_piece(&Position {x: 3, y: 6}, &Position {x: 3, y: 4});
+    return board
+}
+
+#[test]
+fn test_unmake_restores_start_position() {
+    assert_every_move_unmakes(&Board::new());
+}
+
+#[test]
+fn test_unmake_restores_special_moves() {
+    assert_every_move_unmakes(&rich_board());
+}
+
+#[test]
+fn test_unmake_restores_captured_and_moved_state() {
+    let mut board = rich_board();
+    let original = board.clone();
+
+    let en_passant = Move::infer(&board, &Position {x: 4, y: 4}, &Position {x: 3, y: 5}, None).unwrap();
+    assert_eq!(MoveFlag::EnPassant, en_passant.flag);
+    let en_passant_undo = board.make_move(&en_passant);
+    assert!(board.is_empty(&Position {x: 3, y: 4}));
+
+    let castling = Move::infer(&board, &Position {x: 4, y: 7}, &Position {x: 6, y: 7}, None).unwrap();
+    let castling_undo = board.make_move(&castling);
+    assert!(!board.castling_rights().black_king_side);
+
+    let promotion = Move::infer(&board, &Position {x: 1, y: 6}, &Position {x: 0, y: 7}, Some(Queen)).unwrap();
+    let promotion_undo = board.make_move(&promotion);
+
+    board.unmake_move(&promotion, promotion_undo);
+    board.unmake_move(&castling, castling_undo);
+    board.unmake_move(&en_passant, en_passant_undo);
+    assert!(board == original);
+    assert_eq!(Some(Position {x: 3, y: 5}), board.en_passant_target());
+    assert!(board.castling_rights().black_king_side);
+}
+
+#[test]
+fn test_color_sets_follow_moves() {
+    let mut board = rich_board();
+    for (from, to, promotion) in [((4, 4), (3, 5), None), ((4, 7), (6, 7), None), ((1, 6), (0, 7), Some(Queen))] {
+        let mv = Move::infer(&board, &Position {x: from.0, y: from.1}, &Position {x: to.0, y: to.1}, promotion).unwrap();
+        board.make_move(&mv);
+        let (rebuilt, _) = Board::from_fen(&board.to_fen(&White)).unwrap();
+        for color in [White, Black] {
+            assert_eq!(rebuilt.color_set(&color), board.color_set(&color), "{:?} after {:?}", color, mv);
+        }
+    }
+}
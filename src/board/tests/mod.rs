@@ -0,0 +1,5 @@
+mod make_unmake;
+mod zobrist;
+mod piece_code;
+mod fen;
+mod position;
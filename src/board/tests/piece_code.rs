@@ -0,0 +1,45 @@
+use crate::board::{Board, Field, Position};
+use crate::board::piece::{Color, PieceCode, PieceMoved, PieceState, Type};
+
+#[test]
+fn test_piece_code_round_trip() {
+    let mut seen_bits = Vec::new();
+    for color in [Color::White, Color::Black] {
+        for piece_type in Type::ALL {
+            let state = PieceState {piece_type, color, moved: PieceMoved::Yes};
+            let code = PieceCode::from(state);
+            assert_eq!(Some(piece_type), code.piece_type());
+            assert_eq!(Some(color), code.color());
+            assert_eq!(Some(state), code.with_moved(PieceMoved::Yes));
+            assert!(!seen_bits.contains(&code.bits()));
+            seen_bits.push(code.bits());
+        }
+    }
+
+    assert!(PieceCode::EMPTY.is_empty());
+    assert_eq!(None, PieceCode::EMPTY.color());
+    assert_eq!(PieceCode::EMPTY, PieceCode::from(&Field::Empty));
+}
+
+#[test]
+fn test_board_fits_two_cache_lines() {
+    assert!(std::mem::size_of::<Board>() <= 128);
+}
+
+#[test]
+fn test_moved_state_comes_from_board_rights() {
+    let board = Board::new();
+    assert_eq!(PieceMoved::No, board.piece_at(&Position {x: 0, y: 0}).unwrap().moved);
+    assert_eq!(PieceMoved::No, board.piece_at(&Position {x: 3, y: 6}).unwrap().moved);
+    assert_eq!(PieceMoved::Yes, board.piece_at(&Position {x: 1, y: 0}).unwrap().moved);
+
+    let mut board = board;
+    board.move_piece(&Position {x: 4, y: 1}, &Position {x: 4, y: 3});
+    assert_eq!(PieceMoved::LastTurn, board.piece_at(&Position {x: 4, y: 3}).unwrap().moved);
+    board.move_piece(&Position {x: 7, y: 6}, &Position {x: 7, y: 5});
+    board.move_piece(&Position {x: 7, y: 7}, &Position {x: 7, y: 6});
+    board.move_piece(&Position {x: 7, y: 6}, &Position {x: 7, y: 7});
+    assert_eq!(PieceMoved::Yes, board.piece_at(&Position {x: 7, y: 7}).unwrap().moved);
+    assert_eq!(PieceMoved::Yes, board.piece_at(&Position {x: 4, y: 3}).unwrap().moved);
+    assert!(!board.castling_rights().black_king_side);
+}
@@ -0,0 +1,51 @@
+use crate::board::{Board, BOARD_SIZE, Field, Position};
+use crate::board::piece::Color::{Black, White};
+use crate::board::piece::Type::{Bishop, KING, Knight, Pawn, Queen, Rook};
+use crate::errors::ErrorKind;
+
+fn piece_at(board: &Board, name: &str) -> Field {
+    return board.value_at(&name.parse().unwrap())
+}
+
+#[test]
+fn test_square_names() {
+    assert_eq!(Position {x: 0, y: 0}, "a1".parse().unwrap());
+    assert_eq!(Position {x: 4, y: 3}, "e4".parse().unwrap());
+    assert_eq!(Position {x: 7, y: 7}, "h8".parse().unwrap());
+    assert_eq!("e4", Position {x: 4, y: 3}.to_string());
+    assert_eq!(('e', 4), (Position {x: 4, y: 3}.file(), Position {x: 4, y: 3}.rank()));
+    for index in 0..BOARD_SIZE {
+        let pos = Position::from_board_index(index);
+        assert_eq!(pos, pos.to_string().parse().unwrap());
+    }
+}
+
+#[test]
+fn test_invalid_square_names() {
+    for name in ["", "e", "e0", "e9", "i1", "E4", "e44", "4e"] {
+        assert_eq!(Err(ErrorKind::InvalidSquareName), name.parse::<Position>(), "{}", name);
+    }
+}
+
+#[test]
+fn test_names_match_start_position() {
+    let board = Board::new();
+    assert!(matches!(piece_at(&board, "e1"), Field::Piece(state) if state.piece_type == KING && state.color == White));
+    assert!(matches!(piece_at(&board, "d8"), Field::Piece(state) if state.piece_type == Queen && state.color == Black));
+    assert!(matches!(piece_at(&board, "h1"), Field::Piece(state) if state.piece_type == Rook && state.color == White));
+    assert!(matches!(piece_at(&board, "e4"), Field::Empty));
+}
+
+#[test]
+fn test_start_position_back_ranks() {
+    let board = Board::new();
+    let piece = |name: String| board.piece_at(&name.parse().unwrap()).map(|state| (state.piece_type, state.color));
+    let back_rank = [Rook, Knight, Bishop, Queen, KING, Bishop, Knight, Rook];
+    for (file, piece_type) in ('a'..='h').zip(back_rank) {
+        assert_eq!(Some((piece_type, White)), piece(format!("{}1", file)));
+        assert_eq!(Some((Pawn, White)), piece(format!("{}2", file)));
+        assert_eq!(Some((Pawn, Black)), piece(format!("{}7", file)));
+        assert_eq!(Some((piece_type, Black)), piece(format!("{}8", file)));
+    }
+    assert_eq!("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -", board.to_fen(&White));
+}
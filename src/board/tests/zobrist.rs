@@ -0,0 +1,107 @@
+use crate::board::{Board, Field, Position};
+use crate::board::bitboard::BitBoard;
+use crate::board::piece::Color::{Black, White};
+use crate::board::piece::Type::{KING, Pawn};
+use crate::game::Game;
+use crate::moves;
+use crate::moves::{Move, MoveList};
+
+fn play(board: &mut Board, from: Position, to: Position) {
+    let mv = Move::infer(board, &from, &to, None).unwrap();
+    board.make_move(&mv);
+}
+
+#[test]
+fn test_transpositions_have_same_key() {
+    let mut board = Board::new();
+    play(&mut board, Position {x: 4, y: 1}, Position {x: 4, y: 3});
+    play(&mut board, Position {x: 4, y: 6}, Position {x: 4, y: 4});
+    play(&mut board, Position {x: 6, y: 0}, Position {x: 5, y: 2});
+    play(&mut board, Position {x: 1, y: 7}, Position {x: 2, y: 5});
+
+    let mut transposed = Board::new();
+    play(&mut transposed, Position {x: 6, y: 0}, Position {x: 5, y: 2});
+    play(&mut transposed, Position {x: 1, y: 7}, Position {x: 2, y: 5});
+    play(&mut transposed, Position {x: 4, y: 1}, Position {x: 4, y: 3});
+    play(&mut transposed, Position {x: 4, y: 6}, Position {x: 4, y: 4});
+
+    assert_eq!(board.zobrist_key(&White), transposed.zobrist_key(&White));
+    assert_ne!(board.zobrist_key(&White), board.zobrist_key(&Black));
+    assert_ne!(Board::new().zobrist_key(&White), board.zobrist_key(&White));
+}
+
+#[test]
+fn test_en_passant_counts_only_with_pawn_beside() {
+    let pieces = vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+        (Field::new_white(Pawn), Position {x: 3, y: 1}),
+        (Field::new_white(Pawn), Position {x: 6, y: 1}),
+        (Field::new_black(Pawn), Position {x: 4, y: 3}),
+    ];
+    let mut double_step = Board::new_from(pieces.clone());
+    play(&mut double_step, Position {x: 3, y: 1}, Position {x: 3, y: 3});
+    let mut two_single_steps = Board::new_from(pieces.clone());
+    play(&mut two_single_steps, Position {x: 3, y: 1}, Position {x: 3, y: 2});
+    play(&mut two_single_steps, Position {x: 3, y: 2}, Position {x: 3, y: 3});
+    assert_ne!(double_step.zobrist_key(&Black), two_single_steps.zobrist_key(&Black));
+    assert_eq!(double_step.zobrist_key(&Black), BitBoard::from_board(&double_step).zobrist_key(&Black));
+
+    let mut double_step = Board::new_from(pieces.clone());
+    play(&mut double_step, Position {x: 6, y: 1}, Position {x: 6, y: 3});
+    let mut two_single_steps = Board::new_from(pieces);
+    play(&mut two_single_steps, Position {x: 6, y: 1}, Position {x: 6, y: 2});
+    play(&mut two_single_steps, Position {x: 6, y: 2}, Position {x: 6, y: 3});
+    assert_eq!(double_step.zobrist_key(&Black), two_single_steps.zobrist_key(&Black));
+}
+
+#[test]
+fn test_incremental_key_matches_computed_key() {
+    let mut board = Board::new();
+    let mut color = White;
+    for ply in 0..300 {
+        let mut legal_moves = MoveList::new();
+        moves::get_all_legal_moves(&board, &color, &mut legal_moves);
+        if legal_moves.is_empty() {
+            break;
+        }
+
+        let key_before = board.zobrist_key(&color);
+        for mv in &legal_moves {
+            let undo = board.make_move(mv);
+            assert_eq!(board.compute_key(), board.key, "{:?}", mv);
+            assert_eq!(BitBoard::from_board(&board).zobrist_key(&color), board.zobrist_key(&color), "{:?}", mv);
+            board.unmake_move(mv, undo);
+            assert_eq!(key_before, board.zobrist_key(&color));
+        }
+
+        board.make_move(&legal_moves[(ply * 31 + 7) % legal_moves.len()]);
+        color = color.opposite();
+    }
+}
+
+#[test]
+fn test_keys_match_polyglot_reference() {
+    let mut game = Game::new();
+    assert_eq!(0x463b96181691fc9c, game.zobrist_key());
+    for (san, key) in [
+        ("e4", 0x823c9b50fd114196), ("d5", 0x0756b94461c50fb0), ("e5", 0x662fafb965db29d4),
+        ("f5", 0x22a48b5a8e47ff78), ("Ke2", 0x652a607ca3f242c1), ("Kf7", 0x00fdd303c946bdd9),
+    ] {
+        let mv = game.parse_san(san).unwrap();
+        game.play(&mv).unwrap();
+        assert_eq!(key, game.zobrist_key(), "after {}", san);
+    }
+
+    let mut game = Game::new();
+    for san in ["a4", "b5", "h4", "b4", "c4"] {
+        let mv = game.parse_san(san).unwrap();
+        game.play(&mv).unwrap();
+    }
+    assert_eq!(0x3c8123ea7b067637, game.zobrist_key());
+    for san in ["bxc3", "Ra3"] {
+        let mv = game.parse_san(san).unwrap();
+        game.play(&mv).unwrap();
+    }
+    assert_eq!(0x5c3f9b829b279560, game.zobrist_key());
+}
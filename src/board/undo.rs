@@ -0,0 +1,15 @@
+use crate::board::Position;
+use crate::board::castling_rights::CastlingRights;
+use crate::board::piece::PieceCode;
+
+/// Everything [`Board::make_move`](crate::board::Board::make_move) overwrites,
+/// so that [`Board::unmake_move`](crate::board::Board::unmake_move) can restore the board exactly.
+#[derive(Clone, PartialEq)]
+pub struct Undo {
+    pub(crate) moved_piece: PieceCode,
+    pub(crate) captured_pos: Position,
+    pub(crate) captured_piece: PieceCode,
+    pub(crate) castling_rights: CastlingRights,
+    pub(crate) en_passant: Option<u8>,
+    pub(crate) key: u64,
+}
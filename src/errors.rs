@@ -1,15 +1,59 @@
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum ErrorKind {
-    CoordinatesOutsideBoard
+    CoordinatesOutsideBoard,
+    InvalidPromotion,
+    IllegalMove,
+    NotYourTurn,
+    GameOver,
+    InvalidSquareName,
+    InvalidSan,
+    AmbiguousMove,
+    InvalidUci,
+    InvalidPgnTag,
+    UnterminatedPgnComment,
+    UnbalancedPgnVariation,
+    UnexpectedPgnToken,
+    MissingPgnResult,
+    InvalidFenFieldCount,
+    InvalidFenPlacement,
+    InvalidFenKingCount,
+    InvalidFenOpponentInCheck,
+    InvalidFenSideToMove,
+    InvalidFenCastling,
+    InvalidFenEnPassant,
+    InvalidFenClock,
+    PerftTableTooLarge,
 }
 
 impl ErrorKind {
     fn to_str(&self) -> &'static str {
         return match self {
-            ErrorKind::CoordinatesOutsideBoard => "coordinates are outside legal board boundaries"
+            ErrorKind::CoordinatesOutsideBoard => "coordinates are outside legal board boundaries",
+            ErrorKind::InvalidPromotion => "only a pawn reaching the last row can be promoted, to a queen, rook, bishop or knight",
+            ErrorKind::IllegalMove => "move is not legal in this position",
+            ErrorKind::NotYourTurn => "it is the other side's turn to move",
+            ErrorKind::GameOver => "game is already over",
+            ErrorKind::InvalidSquareName => "square names are a file from a to h followed by a rank from 1 to 8",
+            ErrorKind::InvalidSan => "move is not written in standard algebraic notation",
+            ErrorKind::AmbiguousMove => "move fits more than one legal move, add the file or rank of the moving piece",
+            ErrorKind::InvalidUci => "UCI moves are two square names followed by q, r, b or n for promotions, like e2e4 or e7e8q",
+            ErrorKind::InvalidPgnTag => "PGN tags are a name and a quoted value in brackets on one line, like [Event \"Casual\"]",
+            ErrorKind::UnterminatedPgnComment => "PGN comment is not closed by }",
+            ErrorKind::UnbalancedPgnVariation => "PGN variation parentheses do not match",
+            ErrorKind::UnexpectedPgnToken => "PGN move text holds something which is not a move, move number, comment, annotation, variation or result",
+            ErrorKind::MissingPgnResult => "PGN move text does not end with a result: 1-0, 0-1, 1/2-1/2 or *",
+            ErrorKind::InvalidFenFieldCount => "FEN needs placement, side to move, castling and en passant fields, optionally followed by both move counters",
+            ErrorKind::InvalidFenPlacement => "FEN placement needs 8 ranks of 8 squares and no pawns on the first or last rank",
+            ErrorKind::InvalidFenKingCount => "FEN placement needs exactly one king of each color",
+            ErrorKind::InvalidFenOpponentInCheck => "FEN position has the side which just moved in check",
+            ErrorKind::InvalidFenSideToMove => "FEN side to move must be w or b",
+            ErrorKind::InvalidFenCastling => "FEN castling must be - or some of KQkq, each backed by its unmoved king and rook",
+            ErrorKind::InvalidFenEnPassant => "FEN en passant must be - or the square skipped by a pawn of the side which just moved",
+            ErrorKind::InvalidFenClock => "FEN move counters must be numbers, the fullmove number starting at 1",
+            ErrorKind::PerftTableTooLarge => "perft hash table does not fit in memory",
         }
     }
 }
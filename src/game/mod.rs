@@ -0,0 +1,241 @@
+use crate::board::Board;
+use crate::board::fen::fen_clocks;
+use crate::board::piece::Color;
+use crate::board::piece::Type::Pawn;
+use crate::errors::ErrorKind;
+use crate::moves::{get_all_legal_moves, Move, MoveList};
+use crate::moves::game_status::{DrawReason, GameStatus};
+use crate::moves::parallel_perft::{parallel_perft, PerftReport, PerftTable};
+use crate::moves::{perft, san};
+use crate::game::position_key::PositionKey;
+
+pub mod pgn;
+mod position_key;
+
+#[cfg(test)]
+mod tests;
+
+/// Board together with everything needed to play on it by the rules:
+/// whose turn it is, move counters and the moves and positions played so far.
+#[derive(Clone)]
+pub struct Game {
+    board: Board,
+    side_to_move: Color,
+    halfmove_clock: u32,
+    fullmove_number: u32,
+    history: Vec<Move>,
+    position_keys: Vec<PositionKey>,
+}
+
+impl Game {
+    pub fn new() -> Game {
+        return Game::from_board(Board::new(), Color::White)
+    }
+
+    pub fn from_board(board: Board, side_to_move: Color) -> Game {
+        let mut game = Game {
+            board,
+            side_to_move,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            history: Vec::new(),
+            position_keys: Vec::new(),
+        };
+        game.record_position();
+        return game
+    }
+
+    /// Game starting from the position of a full FEN record, move counters included.
+    /// The history starts empty, so repetitions are only counted from this position on.
+    pub fn from_fen(fen: &str) -> Result<Game, ErrorKind> {
+        let (board, side_to_move) = Board::from_fen(fen)?;
+        let (halfmove_clock, fullmove_number) = fen_clocks(fen)?;
+        let mut game = Game::from_board(board, side_to_move);
+        game.halfmove_clock = halfmove_clock;
+        game.fullmove_number = fullmove_number;
+        return Ok(game)
+    }
+
+    pub fn to_fen(&self) -> String {
+        return format!("{} {} {}", self.board.to_fen(&self.side_to_move), self.halfmove_clock, self.fullmove_number)
+    }
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        return Game::new()
+    }
+}
+
+impl Game {
+    #[inline]
+    pub fn board(&self) -> &Board {
+        return &self.board
+    }
+
+    #[inline]
+    pub fn side_to_move(&self) -> Color {
+        return self.side_to_move
+    }
+
+    /// Number of halfmoves since the last capture or pawn move.
+    #[inline]
+    pub fn halfmove_clock(&self) -> u32 {
+        return self.halfmove_clock
+    }
+
+    /// Starts at 1 and is incremented after every black move.
+    #[inline]
+    pub fn fullmove_number(&self) -> u32 {
+        return self.fullmove_number
+    }
+
+    #[inline]
+    pub fn history(&self) -> &[Move] {
+        return &self.history
+    }
+
+    /// Zobrist key of the current position, see [`Board::zobrist_key`].
+    #[inline]
+    pub fn zobrist_key(&self) -> u64 {
+        return self.board.zobrist_key(&self.side_to_move)
+    }
+
+    /// Besides [`Board::game_status`] this also reports the draws which end the game on their own
+    /// because of its course: fivefold repetition and the seventy-five-move rule.
+    /// A draw the side to move may claim, see [`Game::claimable_draw`], is reported as
+    /// [`GameStatus::DrawClaimable`] even when the side to move is in check.
+    pub fn status(&self) -> GameStatus {
+        let board_status = self.board.game_status(&self.side_to_move);
+        if board_status.is_over() {
+            return board_status
+        }
+
+        return if self.repetition_count() >= 5 {
+            GameStatus::Draw(DrawReason::FivefoldRepetition)
+        } else if self.halfmove_clock >= 150 {
+            GameStatus::Draw(DrawReason::SeventyFiveMoveRule)
+        } else if let Some(reason) = self.claimable_draw() {
+            GameStatus::DrawClaimable(reason)
+        } else {
+            board_status
+        }
+    }
+
+    /// Draw the side to move may claim by threefold repetition or the fifty-move rule.
+    pub fn claimable_draw(&self) -> Option<DrawReason> {
+        return if self.repetition_count() >= 3 {
+            Some(DrawReason::ThreefoldRepetition)
+        } else if self.halfmove_clock >= 100 {
+            Some(DrawReason::FiftyMoveRule)
+        } else {
+            None
+        }
+    }
+
+    /// How many times the current position has occurred in the game, counting the current one.
+    pub fn repetition_count(&self) -> usize {
+        return match self.position_keys.last() {
+            None => 0,
+            Some(current) => self.position_keys.iter().filter(|key| *key == current).count()
+        }
+    }
+
+    /// See [`san::to_san`].
+    pub fn to_san(&self, mv: &Move) -> String {
+        return san::to_san(&self.board, mv)
+    }
+
+    /// Legal move of the side to move written as `san`, see [`san::parse_san`].
+    pub fn parse_san(&self, san: &str) -> Result<Move, ErrorKind> {
+        return san::parse_san(&self.board, &self.side_to_move, san)
+    }
+
+    /// Legal move of the side to move written as `uci`, see [`Move::from_uci`].
+    pub fn parse_uci(&self, uci: &str) -> Result<Move, ErrorKind> {
+        return Move::from_uci(&self.board, &self.side_to_move, uci)
+    }
+
+    pub fn legal_moves(&self) -> MoveList {
+        let mut moves = MoveList::new();
+        get_all_legal_moves(&self.board, &self.side_to_move, &mut moves);
+        return moves
+    }
+
+    /// See [`perft::perft`].
+    pub fn perft(&self, depth: usize) -> u64 {
+        return perft::perft(&self.board, &self.side_to_move, depth)
+    }
+
+    /// See [`perft::divide`].
+    pub fn divide(&self, depth: usize) -> Vec<(Move, u64)> {
+        return perft::divide(&self.board, &self.side_to_move, depth)
+    }
+
+    /// See [`parallel_perft`].
+    pub fn parallel_perft(&self, depth: usize, threads: usize, table: Option<&PerftTable>) -> PerftReport {
+        return parallel_perft(&self.board, &self.side_to_move, depth, threads, table)
+    }
+
+    /// Plays `mv` for the side to move and returns the status of the game after it.
+    pub fn play(&mut self, mv: &Move) -> Result<GameStatus, ErrorKind> {
+        if self.status().is_over() {
+            return Err(ErrorKind::GameOver)
+        }
+        if mv.piece.color != self.side_to_move {
+            return Err(ErrorKind::NotYourTurn)
+        }
+        if !self.legal_moves().contains(mv) {
+            return Err(ErrorKind::IllegalMove)
+        }
+
+        self.board.apply_move(mv);
+        if mv.piece.piece_type == Pawn || mv.is_capture() {
+            self.halfmove_clock = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+        if self.side_to_move == Color::Black {
+            self.fullmove_number += 1;
+        }
+        self.side_to_move = self.side_to_move.opposite();
+        self.history.push(mv.clone());
+        self.record_position();
+
+        return Ok(self.status())
+    }
+
+    /// What [`Game::take_back`] needs to return to the current position after one move.
+    pub(crate) fn snapshot(&self) -> GameSnapshot {
+        return GameSnapshot {
+            board: self.board.clone(),
+            side_to_move: self.side_to_move,
+            halfmove_clock: self.halfmove_clock,
+            fullmove_number: self.fullmove_number,
+        }
+    }
+
+    /// Takes back the last move played, `snapshot` being taken right before it.
+    pub(crate) fn take_back(&mut self, snapshot: GameSnapshot) {
+        self.history.pop();
+        self.position_keys.pop();
+        self.board = snapshot.board;
+        self.side_to_move = snapshot.side_to_move;
+        self.halfmove_clock = snapshot.halfmove_clock;
+        self.fullmove_number = snapshot.fullmove_number;
+    }
+
+    fn record_position(&mut self) {
+        let key = PositionKey::new(&self.board, self.side_to_move, &self.legal_moves());
+        self.position_keys.push(key);
+    }
+}
+
+/// Position and counters of a [`Game`] without the moves and positions before it.
+#[derive(Clone)]
+pub(crate) struct GameSnapshot {
+    board: Board,
+    side_to_move: Color,
+    halfmove_clock: u32,
+    fullmove_number: u32,
+}
@@ -0,0 +1,391 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use crate::errors::ErrorKind;
+use crate::game::Game;
+use crate::moves::Move;
+
+/// Tags every PGN game is expected to have, in the order they are exported.
+pub const SEVEN_TAG_ROSTER: [&str; 7] = ["Event", "Site", "Date", "Round", "White", "Black", "Result"];
+
+/// Game read from PGN, with its main line replayed and checked move by move.
+#[derive(Clone, Debug)]
+pub struct PgnGame {
+    /// Tags in the order they were read, the seven tag roster as well as any other.
+    pub tags: Vec<(String, String)>,
+    pub moves: Vec<PgnMove>,
+    pub result: PgnResult,
+}
+
+impl PgnGame {
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        return self.tags.iter().find(|(tag, _)| tag == name).map(|(_, value)| value.as_str())
+    }
+
+    /// Game at the position the tags start it from, the start position unless a "FEN" tag is given.
+    pub fn start(&self) -> Result<Game, ErrorKind> {
+        return match self.tag("FEN") {
+            Some(fen) => Game::from_fen(fen),
+            None => Ok(Game::new()),
+        }
+    }
+
+    /// Game after playing the main line.
+    pub fn replay(&self) -> Result<Game, ErrorKind> {
+        let mut game = self.start()?;
+        for pgn_move in &self.moves {
+            game.play(&pgn_move.mv)?;
+        }
+        return Ok(game)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PgnMove {
+    pub mv: Move,
+    /// The move as [`Game::to_san`] writes it, which may differ from how the file wrote it.
+    pub san: String,
+    /// Numeric annotation glyphs, "!" and "?" suffixes included as $1 to $6.
+    pub nags: Vec<u8>,
+    /// Comments in front of the move, only found before the first move of a line.
+    pub comments_before: Vec<String>,
+    pub comments: Vec<String>,
+    /// Lines played instead of this move, each starting from the position before it.
+    pub variations: Vec<Vec<PgnMove>>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum PgnResult {
+    WhiteWins,
+    BlackWins,
+    Draw,
+    /// Game still going on or result not known, written as "*".
+    Unknown,
+}
+
+impl PgnResult {
+    fn from_token(token: &str) -> Option<PgnResult> {
+        return match token {
+            "1-0" => Some(PgnResult::WhiteWins),
+            "0-1" => Some(PgnResult::BlackWins),
+            "1/2-1/2" => Some(PgnResult::Draw),
+            "*" => Some(PgnResult::Unknown),
+            _ => None,
+        }
+    }
+}
+
+/// Error of a PGN input together with where it starts, lines and columns counted from 1.
+#[derive(Debug, PartialEq)]
+pub struct PgnError {
+    pub kind: ErrorKind,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for PgnError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return write!(f, "line {}, column {}: {}", self.line, self.column, self.kind)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Token {
+    Tag(String, String),
+    MoveNumber,
+    San(String),
+    Nag(u8),
+    Comment(String),
+    VariationStart,
+    VariationEnd,
+    Result(PgnResult),
+}
+
+/// Reads games one after another from `reader`, only holding the line being read and the game being built.
+/// After an error the reader skips to the tags of the next game.
+pub struct PgnReader<R: BufRead> {
+    reader: R,
+    line: Vec<char>,
+    line_number: usize,
+    column: usize,
+    is_at_end: bool,
+    peeked: Option<(Token, usize, usize)>,
+}
+
+impl<R: BufRead> PgnReader<R> {
+    pub fn new(reader: R) -> PgnReader<R> {
+        return PgnReader {reader, line: Vec::new(), line_number: 0, column: 0, is_at_end: false, peeked: None}
+    }
+
+    fn read_game(&mut self) -> Option<Result<PgnGame, PgnError>> {
+        let mut tags = Vec::new();
+        let mut comments = Vec::new();
+        loop {
+            match self.peek_token() {
+                Ok(None) if tags.is_empty() && comments.is_empty() => return None,
+                Ok(Some((Token::Tag(..) | Token::Comment(..), ..))) => match self.next_token() {
+                    Ok(Some((Token::Tag(name, value), ..))) => tags.push((name, value)),
+                    Ok(Some((Token::Comment(comment), ..))) => comments.push(comment),
+                    _ => {}
+                },
+                Ok(_) => break,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+
+        let mut game = PgnGame {tags, moves: Vec::new(), result: PgnResult::Unknown};
+        let (line, column) = (self.line_number, self.column + 1);
+        let start = match game.start() {
+            Ok(start) => start,
+            Err(kind) => return Some(Err(PgnError {kind, line, column})),
+        };
+        return Some(self.read_line(&start, comments, false).map(|(moves, result)| {
+            game.moves = moves;
+            game.result = result.unwrap_or(PgnResult::Unknown);
+            return game
+        }))
+    }
+
+    /// Moves of the main line or a variation starting at `start`, up to the result token or the
+    /// closing parenthesis of a variation.
+    fn read_line(&mut self, start: &Game, mut pending_comments: Vec<String>, is_variation: bool)
+                 -> Result<(Vec<PgnMove>, Option<PgnResult>), PgnError> {
+        let mut game = start.clone();
+        let mut before_last_move = None;
+        let mut moves: Vec<PgnMove> = Vec::new();
+        loop {
+            let (token, line, column) = match self.next_token()? {
+                Some(token) => token,
+                None if is_variation => return Err(self.error(ErrorKind::UnbalancedPgnVariation)),
+                None => return Err(self.error(ErrorKind::MissingPgnResult)),
+            };
+            let error = |kind| PgnError {kind, line, column};
+            match (token, moves.last_mut()) {
+                (Token::MoveNumber, _) => {}
+                (Token::San(san), _) => {
+                    let mv = game.parse_san(&san).map_err(|kind| match kind {
+                        ErrorKind::IllegalMove if game.status().is_over() => error(ErrorKind::GameOver),
+                        _ => error(kind),
+                    })?;
+                    let san = game.to_san(&mv);
+                    before_last_move = Some(game.snapshot());
+                    game.play(&mv).map_err(error)?;
+                    moves.push(PgnMove {
+                        mv,
+                        san,
+                        nags: Vec::new(),
+                        comments_before: std::mem::take(&mut pending_comments),
+                        comments: Vec::new(),
+                        variations: Vec::new(),
+                    });
+                }
+                (Token::Nag(nag), Some(last_move)) => last_move.nags.push(nag),
+                (Token::Comment(comment), Some(last_move)) => last_move.comments.push(comment),
+                (Token::Comment(comment), None) => pending_comments.push(comment),
+                (Token::VariationStart, Some(_)) => {
+                    let mut variation_start = game.clone();
+                    if let Some(snapshot) = before_last_move.clone() {
+                        variation_start.take_back(snapshot);
+                    }
+                    let (variation, _) = self.read_line(&variation_start, Vec::new(), true)?;
+                    if let Some(last_move) = moves.last_mut() {
+                        last_move.variations.push(variation);
+                    }
+                }
+                (Token::VariationEnd, _) if is_variation => return Ok((moves, None)),
+                (Token::VariationEnd, _) => return Err(error(ErrorKind::UnbalancedPgnVariation)),
+                (Token::Result(result), _) if !is_variation => return Ok((moves, Some(result))),
+                (Token::Result(_), _) => return Err(error(ErrorKind::UnbalancedPgnVariation)),
+                (tag @ Token::Tag(..), _) if !is_variation => {
+                    // The tag already belongs to the next game, which is read from it on.
+                    self.peeked = Some((tag, line, column));
+                    return Err(error(ErrorKind::MissingPgnResult))
+                }
+                _ => return Err(error(ErrorKind::UnexpectedPgnToken)),
+            }
+        }
+    }
+
+    /// Skips what is left of a game that could not be read, up to the first tag after its move text.
+    fn skip_to_next_game(&mut self) {
+        self.peeked = None;
+        let mut is_in_move_text = false;
+        loop {
+            if self.column >= self.line.len() && !self.read_next_line() {
+                return
+            }
+            let starts_tag = self.line.iter().find(|symbol| !symbol.is_whitespace()) == Some(&'[');
+            if starts_tag && is_in_move_text {
+                self.column = 0;
+                return
+            }
+            is_in_move_text |= !starts_tag;
+            self.column = self.line.len();
+        }
+    }
+
+    fn peek_token(&mut self) -> Result<Option<&(Token, usize, usize)>, PgnError> {
+        if self.peeked.is_none() {
+            self.peeked = self.read_token()?;
+        }
+        return Ok(self.peeked.as_ref())
+    }
+
+    fn next_token(&mut self) -> Result<Option<(Token, usize, usize)>, PgnError> {
+        return match self.peeked.take() {
+            Some(token) => Ok(Some(token)),
+            None => self.read_token(),
+        }
+    }
+
+    /// Next token with the line and column it starts at.
+    fn read_token(&mut self) -> Result<Option<(Token, usize, usize)>, PgnError> {
+        let symbol = loop {
+            match self.peek_char() {
+                None => return Ok(None),
+                Some('%') if self.column == 0 => self.column = self.line.len(),
+                // Some writers put "e.p." after en passant captures, it adds nothing to the move.
+                Some('e') if self.line[self.column..].starts_with(&['e', '.', 'p', '.']) => self.column += 4,
+                Some(symbol) if symbol.is_whitespace() => self.column += 1,
+                Some(symbol) => break symbol,
+            }
+        };
+
+        let (line, column) = (self.line_number, self.column + 1);
+        let error = |kind| PgnError {kind, line, column};
+        self.column += 1;
+        let token = match symbol {
+            '[' => self.read_tag().ok_or_else(|| error(ErrorKind::InvalidPgnTag))?,
+            '{' => self.read_comment().ok_or_else(|| error(ErrorKind::UnterminatedPgnComment))?,
+            ';' => Token::Comment(self.read_while(|_| true).trim().to_string()),
+            '(' => Token::VariationStart,
+            ')' => Token::VariationEnd,
+            '$' => {
+                let digits = self.read_while(|symbol| symbol.is_ascii_digit());
+                Token::Nag(digits.parse().map_err(|_| error(ErrorKind::UnexpectedPgnToken))?)
+            }
+            '!' | '?' => {
+                let suffix = format!("{}{}", symbol, self.read_while(|symbol| symbol == '!' || symbol == '?'));
+                let nag = ["!", "?", "!!", "??", "!?", "?!"].iter().position(|glyph| *glyph == suffix);
+                Token::Nag(nag.ok_or_else(|| error(ErrorKind::UnexpectedPgnToken))? as u8 + 1)
+            }
+            _ if symbol.is_ascii_alphanumeric() || symbol == '*' => {
+                let text = format!("{}{}", symbol, self.read_while(|symbol| symbol.is_ascii_alphanumeric() || "_+#=:-/*".contains(symbol)));
+                self.read_symbol_end(text).ok_or_else(|| error(ErrorKind::UnexpectedPgnToken))?
+            }
+            _ => return Err(error(ErrorKind::UnexpectedPgnToken)),
+        };
+        return Ok(Some((token, line, column)))
+    }
+
+    /// Tells move numbers, results and moves apart.
+    fn read_symbol_end(&mut self, text: String) -> Option<Token> {
+        if text.chars().all(|symbol| symbol.is_ascii_digit()) {
+            self.read_while(|symbol| symbol == '.');
+            return Some(Token::MoveNumber)
+        }
+        if let Some(result) = PgnResult::from_token(&text) {
+            return Some(Token::Result(result))
+        }
+        return if self.peek_char() == Some('.') { None } else { Some(Token::San(text)) }
+    }
+
+    fn read_tag(&mut self) -> Option<Token> {
+        self.read_while(|symbol| symbol == ' ' || symbol == '\t');
+        let name = self.read_while(|symbol| symbol.is_ascii_alphanumeric() || symbol == '_');
+        self.read_while(|symbol| symbol == ' ' || symbol == '\t');
+        if name.is_empty() || self.next_char_on_line()? != '"' {
+            return None
+        }
+        let mut value = String::new();
+        loop {
+            match self.next_char_on_line()? {
+                '"' => break,
+                '\\' => value.push(self.next_char_on_line()?),
+                symbol => value.push(symbol),
+            }
+        }
+        self.read_while(|symbol| symbol == ' ' || symbol == '\t');
+        return if self.next_char_on_line()? == ']' { Some(Token::Tag(name, value)) } else { None }
+    }
+
+    /// Comment up to the closing brace, which may be lines further down.
+    fn read_comment(&mut self) -> Option<Token> {
+        let mut comment = String::new();
+        loop {
+            if self.column >= self.line.len() {
+                if !self.read_next_line() {
+                    return None
+                }
+                comment.push('\n');
+                continue;
+            }
+            let symbol = self.line[self.column];
+            self.column += 1;
+            if symbol == '}' {
+                return Some(Token::Comment(comment.trim().to_string()))
+            }
+            comment.push(symbol);
+        }
+    }
+
+    fn read_while(&mut self, accept: impl Fn(char) -> bool) -> String {
+        let start = self.column;
+        while self.column < self.line.len() && accept(self.line[self.column]) {
+            self.column += 1;
+        }
+        return self.line[start..self.column].iter().collect()
+    }
+
+    fn next_char_on_line(&mut self) -> Option<char> {
+        let symbol = *self.line.get(self.column)?;
+        self.column += 1;
+        return Some(symbol)
+    }
+
+    /// Next character of the input, reading the next line once the current one is used up.
+    fn peek_char(&mut self) -> Option<char> {
+        while self.column >= self.line.len() {
+            if !self.read_next_line() {
+                return None
+            }
+        }
+        return Some(self.line[self.column])
+    }
+
+    fn read_next_line(&mut self) -> bool {
+        if self.is_at_end {
+            return false
+        }
+        let mut text = String::new();
+        match self.reader.read_line(&mut text) {
+            Ok(0) | Err(_) => {
+                self.is_at_end = true;
+                return false
+            }
+            Ok(_) => {
+                self.line = text.chars().collect();
+                self.line_number += 1;
+                self.column = 0;
+                return true
+            }
+        }
+    }
+
+    fn error(&self, kind: ErrorKind) -> PgnError {
+        return PgnError {kind, line: self.line_number.max(1), column: self.column + 1}
+    }
+}
+
+impl<R: BufRead> Iterator for PgnReader<R> {
+    type Item = Result<PgnGame, PgnError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let game = self.read_game()?;
+        let is_at_next_game = matches!(self.peeked, Some((Token::Tag(..), ..)));
+        if game.is_err() && !is_at_next_game {
+            self.skip_to_next_game();
+        }
+        return Some(game)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::board::{Board, BOARD_SIZE, BOARD_WIDTH, Position};
+use crate::board::castling_rights::CastlingRights;
+use crate::board::piece::{Color, Type};
+use crate::moves::{Move, MoveFlag};
+
+/// Everything that makes two positions the same for the repetition rules: placement of the pieces,
+/// side to move, castling rights and the possibility of taking en passant.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PositionKey {
+    pieces: [Option<(Type, Color)>; BOARD_SIZE],
+    side_to_move: Color,
+    castling_rights: CastlingRights,
+    en_passant: Option<Position>,
+}
+
+impl PositionKey {
+    /// `legal_moves` are the moves of the side to move, en passant square counts
+    /// only when one of them is an en passant capture.
+    pub fn new(board: &Board, side_to_move: Color, legal_moves: &[Move]) -> PositionKey {
+        let mut pieces = [None; BOARD_SIZE];
+        for (index, piece) in pieces.iter_mut().enumerate() {
+            *piece = board.piece_at(&Position {x: index % BOARD_WIDTH, y: index / BOARD_WIDTH})
+                .map(|state| (state.piece_type, state.color));
+        }
+
+        return PositionKey {
+            pieces,
+            side_to_move,
+            castling_rights: board.castling_rights(),
+            en_passant: legal_moves.iter()
+                .find(|mv| mv.flag == MoveFlag::EnPassant)
+                .map(|mv| mv.to.clone()),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::board::{Board, Field, Position};
+use crate::board::piece::Color::White;
+use crate::board::piece::Type::{Bishop, KING, Knight, Pawn, Rook};
+use crate::errors::ErrorKind;
+use crate::game::Game;
+use crate::moves::Move;
+use crate::moves::game_status::{DrawReason, GameStatus};
+
+fn play(game: &mut Game, from: Position, to: Position) -> Result<GameStatus, ErrorKind> {
+    let mv = Move::infer(game.board(), &from, &to, None).unwrap();
+    return game.play(&mv)
+}
+
+fn shuffle_knights(game: &mut Game) -> Result<GameStatus, ErrorKind> {
+    play(game, Position {x: 6, y: 0}, Position {x: 5, y: 2})?;
+    play(game, Position {x: 6, y: 7}, Position {x: 5, y: 5})?;
+    play(game, Position {x: 5, y: 2}, Position {x: 6, y: 0})?;
+    return play(game, Position {x: 5, y: 5}, Position {x: 6, y: 7})
+}
+
+#[test]
+fn test_threefold_and_fivefold_repetition() {
+    let mut game = Game::new();
+
+    assert!(matches!(shuffle_knights(&mut game), Ok(GameStatus::Ongoing)));
+    assert_eq!(2, game.repetition_count());
+    assert_eq!(None, game.claimable_draw());
+
+    let status = shuffle_knights(&mut game);
+    assert!(matches!(status, Ok(GameStatus::DrawClaimable(DrawReason::ThreefoldRepetition))));
+    assert_eq!(Some(DrawReason::ThreefoldRepetition), game.claimable_draw());
+    assert!(!game.status().is_over());
+
+    assert!(matches!(shuffle_knights(&mut game), Ok(GameStatus::DrawClaimable(DrawReason::ThreefoldRepetition))));
+    assert!(matches!(shuffle_knights(&mut game), Ok(GameStatus::Draw(DrawReason::FivefoldRepetition))));
+    assert_eq!(5, game.repetition_count());
+    assert!(matches!(play(&mut game, Position {x: 6, y: 0}, Position {x: 5, y: 2}), Err(ErrorKind::GameOver)));
+}
+
+#[test]
+fn test_lost_castling_right_is_different_position() {
+    let mut game = Game::from_board(Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Rook), Position {x: 7, y: 0}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+    ]), White);
+
+    assert!(play(&mut game, Position {x: 7, y: 0}, Position {x: 7, y: 1}).is_ok());
+    assert!(play(&mut game, Position {x: 4, y: 7}, Position {x: 4, y: 6}).is_ok());
+    assert!(play(&mut game, Position {x: 7, y: 1}, Position {x: 7, y: 0}).is_ok());
+    assert!(play(&mut game, Position {x: 4, y: 6}, Position {x: 4, y: 7}).is_ok());
+    assert_eq!(1, game.repetition_count());
+}
+
+#[test]
+fn test_fifty_and_seventy_five_move_rules() {
+    let mut game = Game::new();
+    game.halfmove_clock = 98;
+
+    assert!(play(&mut game, Position {x: 6, y: 0}, Position {x: 5, y: 2}).is_ok());
+    assert_eq!(None, game.claimable_draw());
+    let status = play(&mut game, Position {x: 6, y: 7}, Position {x: 5, y: 5});
+    assert!(matches!(status, Ok(GameStatus::DrawClaimable(DrawReason::FiftyMoveRule))));
+    assert_eq!(Some(DrawReason::FiftyMoveRule), game.claimable_draw());
+
+    game.halfmove_clock = 149;
+    let status = play(&mut game, Position {x: 1, y: 0}, Position {x: 2, y: 2});
+    assert!(matches!(status, Ok(GameStatus::Draw(DrawReason::SeventyFiveMoveRule))));
+}
+
+#[test]
+fn test_insufficient_material() {
+    let king_and_knight = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Knight), Position {x: 1, y: 0}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+    ]);
+    assert_eq!(GameStatus::Draw(DrawReason::InsufficientMaterial), king_and_knight.game_status(&White));
+
+    let same_colored_bishops = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Bishop), Position {x: 2, y: 0}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+        (Field::new_black(Bishop), Position {x: 5, y: 7}),
+    ]);
+    assert!(same_colored_bishops.has_insufficient_material());
+
+    let opposite_colored_bishops = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Bishop), Position {x: 2, y: 0}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+        (Field::new_black(Bishop), Position {x: 2, y: 7}),
+    ]);
+    assert_eq!(GameStatus::Ongoing, opposite_colored_bishops.game_status(&White));
+
+    let two_knights = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Knight), Position {x: 1, y: 0}),
+        (Field::new_white(Knight), Position {x: 6, y: 0}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+    ]);
+    assert!(!two_knights.has_insufficient_material());
+
+    let pawn = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Pawn), Position {x: 1, y: 1}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+    ]);
+    assert!(!pawn.has_insufficient_material());
+}
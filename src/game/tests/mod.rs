@@ -0,0 +1,3 @@
+mod play;
+mod draw;
+mod pgn;
@@ -0,0 +1,109 @@
+use crate::board::piece::Color::White;
+use crate::errors::ErrorKind;
+use crate::game::pgn::{PgnError, PgnGame, PgnReader, PgnResult, SEVEN_TAG_ROSTER};
+use crate::moves::game_status::GameStatus;
+
+const EVERGREEN: &str = r#"[Event "Casual Game"]
+[Site "Berlin GER"]
+[Date "1852.??.??"]
+[Round "?"]
+[White "Anderssen, Adolf"]
+[Black "Dufresne, Jean"]
+[Result "1-0"]
+[Annotator "Someone \"quoted\""]
+
+{The Evergreen game.}
+1.e4 e5 2.Nf3 Nc6 3.Bc4 Bc5 4.b4 Bxb4 5.c3 Ba5 6.d4 exd4 7.O-O!? d3 $6
+(7...dxc3 8.Qb3 (8.Nxc3) Qf6) 8.Qb3 Qf6 ; the queen comes out
+9.e5 Qg6 10.Re1 Nge7 11.Ba3 b5 12.Qxb5 Rb8 13.Qa4 Bb6 14.Nbd2 Bb7 15.Ne4 Qf5
+16.Bxd3 Qh5 17.Nf6+ gxf6 18.exf6 Rg8 19.Rad1 Qxf3 20.Rxe7+ Nxe7 21.Qxd7+ Kxd7
+22.Bf5+ Ke8 23.Bd7+ Kf8 24.Bxe7# 1-0
+"#;
+
+fn read_all(pgn: &str) -> Vec<Result<PgnGame, PgnError>> {
+    return PgnReader::new(pgn.as_bytes()).collect()
+}
+
+fn first_error(pgn: &str) -> PgnError {
+    return read_all(pgn).into_iter().next().unwrap().unwrap_err()
+}
+
+#[test]
+fn test_tags_and_main_line() {
+    let games = read_all(EVERGREEN);
+    assert_eq!(1, games.len());
+    let game = games[0].as_ref().unwrap();
+    for tag in SEVEN_TAG_ROSTER {
+        assert!(game.tag(tag).is_some(), "{}", tag);
+    }
+    assert_eq!(Some("Anderssen, Adolf"), game.tag("White"));
+    assert_eq!(Some("Someone \"quoted\""), game.tag("Annotator"));
+    assert_eq!(PgnResult::WhiteWins, game.result);
+    assert_eq!(47, game.moves.len());
+    assert_eq!("Bxe7#", game.moves[46].san);
+    assert!(matches!(game.replay().unwrap().status(), GameStatus::Checkmate(White)));
+}
+
+#[test]
+fn test_comments_nags_and_variations() {
+    let game = read_all(EVERGREEN).remove(0).unwrap();
+    assert_eq!(vec!["The Evergreen game.".to_string()], game.moves[0].comments_before);
+    assert_eq!(vec![5], game.moves[12].nags);
+    assert_eq!(vec![6], game.moves[13].nags);
+    assert_eq!(vec!["the queen comes out".to_string()], game.moves[15].comments);
+
+    let variation = &game.moves[13].variations[0];
+    let sans: Vec<&str> = variation.iter().map(|pgn_move| pgn_move.san.as_str()).collect();
+    assert_eq!(vec!["dxc3", "Qb3", "Qf6"], sans);
+    assert_eq!("Nxc3", variation[1].variations[0][0].san);
+}
+
+#[test]
+fn test_several_games_and_start_position() {
+    let pgn = "[Event \"First\"]\n\n1. e4 *\n\n% escaped line\n[Event \"Second\"]\n[SetUp \"1\"]\n\
+               [FEN \"4k3/8/8/8/8/8/4P3/4K3 b - - 0 40\"]\n\n40... Kd7 41. e4 1/2-1/2\n";
+    let games = read_all(pgn);
+    assert_eq!(2, games.len());
+    let first = games[0].as_ref().unwrap();
+    assert_eq!((1, PgnResult::Unknown), (first.moves.len(), first.result));
+    let second = games[1].as_ref().unwrap();
+    assert_eq!(Some("Second"), second.tag("Event"));
+    assert_eq!(PgnResult::Draw, second.result);
+    assert_eq!("8/3k4/8/8/4P3/8/8/4K3 b - e3 0 41", second.replay().unwrap().to_fen());
+}
+
+#[test]
+fn test_error_positions() {
+    assert_eq!(PgnError {kind: ErrorKind::IllegalMove, line: 4, column: 4}, first_error("[Event \"?\"]\n\n1. e4 e5\n2. Ke3 *\n"));
+    assert_eq!(PgnError {kind: ErrorKind::InvalidSan, line: 1, column: 4}, first_error("1. x4 *"));
+    assert_eq!(PgnError {kind: ErrorKind::AmbiguousMove, line: 1, column: 34},
+               first_error("1. Nf3 d5 2. Nc3 d4 3. Ne4 d3 4. Ng5 *"));
+    assert_eq!(PgnError {kind: ErrorKind::UnterminatedPgnComment, line: 1, column: 7}, first_error("1. e4 {never\nclosed"));
+    assert_eq!(PgnError {kind: ErrorKind::UnbalancedPgnVariation, line: 1, column: 7}, first_error("1. e4 ) *"));
+    assert_eq!(PgnError {kind: ErrorKind::UnbalancedPgnVariation, line: 1, column: 14}, first_error("1. e4 (1. d4 *"));
+    assert_eq!(PgnError {kind: ErrorKind::UnexpectedPgnToken, line: 1, column: 1}, first_error("(1. e4) *"));
+    assert_eq!(PgnError {kind: ErrorKind::MissingPgnResult, line: 1, column: 7}, first_error("1. e4\n"));
+    assert_eq!(PgnError {kind: ErrorKind::InvalidPgnTag, line: 2, column: 1}, first_error("[Event \"a\"]\n[Site a]\n1. e4 *"));
+    assert_eq!(PgnError {kind: ErrorKind::GameOver, line: 1, column: 24},
+               first_error("1. f3 e5 2. g4 Qh4# 3. a3 0-1"));
+}
+
+#[test]
+fn test_continues_after_broken_game() {
+    let pgn = "[Event \"Broken\"]\n\n1. e4 e4 *\n\n[Event \"Fine\"]\n\n1. d4 d5 *\n";
+    let games = read_all(pgn);
+    assert_eq!(2, games.len());
+    assert_eq!(ErrorKind::IllegalMove, games[0].as_ref().unwrap_err().kind);
+    assert_eq!(Some("Fine"), games[1].as_ref().unwrap().tag("Event"));
+}
+
+#[test]
+fn test_game_without_result_keeps_next_game() {
+    let pgn = "[Event \"A\"]\n\n1. e4 e5\n\n[Event \"B\"]\n\n1. d4 d5 *\n\n[Event \"C\"]\n\n1. c4 *\n";
+    let games = read_all(pgn);
+    assert_eq!(3, games.len());
+    assert_eq!(&PgnError {kind: ErrorKind::MissingPgnResult, line: 5, column: 1}, games[0].as_ref().unwrap_err());
+    assert_eq!(Some("B"), games[1].as_ref().unwrap().tag("Event"));
+    assert_eq!(2, games[1].as_ref().unwrap().moves.len());
+    assert_eq!(Some("C"), games[2].as_ref().unwrap().tag("Event"));
+}
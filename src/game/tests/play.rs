@@ -0,0 +1,71 @@
+use crate::board::Position;
+use crate::board::piece::Color::{Black, White};
+use crate::errors::ErrorKind;
+use crate::game::Game;
+use crate::moves::Move;
+use crate::moves::game_status::GameStatus;
+
+fn play(game: &mut Game, from: Position, to: Position) -> Result<GameStatus, ErrorKind> {
+    let mv = Move::infer(game.board(), &from, &to, None).unwrap();
+    return game.play(&mv)
+}
+
+#[test]
+fn test_new_game() {
+    let game = Game::new();
+
+    assert_eq!(White, game.side_to_move());
+    assert_eq!(0, game.halfmove_clock());
+    assert_eq!(1, game.fullmove_number());
+    assert_eq!(20, game.legal_moves().len());
+    assert!(game.history().is_empty());
+}
+
+#[test]
+fn test_turns_and_counters() {
+    let mut game = Game::new();
+
+    assert!(play(&mut game, Position {x: 6, y: 0}, Position {x: 5, y: 2}).is_ok());
+    assert_eq!(Black, game.side_to_move());
+    assert_eq!(1, game.halfmove_clock());
+    assert_eq!(1, game.fullmove_number());
+
+    assert!(play(&mut game, Position {x: 4, y: 6}, Position {x: 4, y: 4}).is_ok());
+    assert_eq!(White, game.side_to_move());
+    assert_eq!(0, game.halfmove_clock());
+    assert_eq!(2, game.fullmove_number());
+    assert_eq!(2, game.history().len());
+}
+
+#[test]
+fn test_out_of_turn_move_is_refused() {
+    let mut game = Game::new();
+
+    let result = play(&mut game, Position {x: 4, y: 6}, Position {x: 4, y: 4});
+    assert!(matches!(result, Err(ErrorKind::NotYourTurn)));
+    assert_eq!(White, game.side_to_move());
+    assert!(game.history().is_empty());
+}
+
+#[test]
+fn test_illegal_move_is_refused() {
+    let mut game = Game::new();
+
+    let result = play(&mut game, Position {x: 4, y: 1}, Position {x: 4, y: 4});
+    assert!(matches!(result, Err(ErrorKind::IllegalMove)));
+    assert!(game.board().is_empty(&Position {x: 4, y: 4}));
+}
+
+#[test]
+fn test_fools_mate_ends_game() {
+    let mut game = Game::new();
+
+    assert!(play(&mut game, Position {x: 5, y: 1}, Position {x: 5, y: 2}).is_ok());
+    assert!(play(&mut game, Position {x: 4, y: 6}, Position {x: 4, y: 4}).is_ok());
+    assert!(play(&mut game, Position {x: 6, y: 1}, Position {x: 6, y: 3}).is_ok());
+    let status = play(&mut game, Position {x: 3, y: 7}, Position {x: 7, y: 3});
+    assert!(matches!(status, Ok(GameStatus::Checkmate(Black))));
+
+    let after_mate = play(&mut game, Position {x: 0, y: 1}, Position {x: 0, y: 2});
+    assert!(matches!(after_mate, Err(ErrorKind::GameOver)));
+}
@@ -0,0 +1,6 @@
+pub mod board;
+pub mod moves;
+pub mod errors;
+pub mod game;
+#[cfg(test)]
+mod test_utils;
@@ -1,35 +1,106 @@
-use crate::board::{Board, Position};
-use crate::moves::get_moves;
+use std::time::Instant;
+use rust_chess::board::Position;
+use rust_chess::game::Game;
+use rust_chess::moves::Move;
+use rust_chess::moves::parallel_perft::PerftTable;
 
-mod board;
-mod moves;
-mod errors;
+const USAGE: &str = "usage: rust-chess [perft <depth> [--threads <n>] [--hash <mb>] | divide <depth>]";
 
 fn main() {
-    let mut board = Board::new();
-    let mut moves: Vec<Position> = Vec::new();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("perft") => run_perft(&args[1..], false),
+        Some("divide") => run_perft(&args[1..], true),
+        Some(command) => exit_with_usage_error(&format!("unknown command {}, {}", command, USAGE)),
+        None => play_demo(),
+    }
+}
+
+/// Counts the positions reachable from the start position, split by the first move when `split` is set.
+fn run_perft(args: &[String], split: bool) {
+    let depth = match args.first().map(|depth| depth.parse::<usize>()) {
+        Some(Ok(depth)) => depth,
+        _ => exit_with_usage_error("depth must be a non-negative number"),
+    };
+
+    let game = Game::new();
+    if !split {
+        run_parallel_perft(&game, depth, &args[1..]);
+        return;
+    }
+    if let Some(extra) = args.get(1) {
+        exit_with_usage_error(&format!("unexpected argument {}, {}", extra, USAGE));
+    }
 
-    println!("{}", board);
-    get_moves(&board, &Position {x: 1, y: 1}, &mut moves);
-    for pos in &moves {
-        println!("x: {}, y: {}", pos.x, pos.y);
+    let start = Instant::now();
+    let counts = game.divide(depth);
+    for (mv, nodes) in &counts {
+        println!("{}: {}", mv.to_uci(), nodes);
     }
-    moves.clear();
+    let nodes: u64 = counts.iter().map(|(_, nodes)| nodes).sum();
+    println!("\nnodes: {}, time: {} ms", nodes, start.elapsed().as_millis());
+}
 
-    board.move_piece(&Position {x: 0, y: 6}, &Position {x: 0, y: 2});
-    println!("\n{}", board);
-    get_moves(&board, &Position {x: 1, y: 1}, &mut moves);
-    for pos in &moves {
-        println!("x: {}, y: {}", pos.x, pos.y);
+/// Perft on all available cores unless `--threads` says otherwise, caching subtree counts when `--hash` is given.
+fn run_parallel_perft(game: &Game, depth: usize, options: &[String]) {
+    let mut threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+    let mut hash_mb = None;
+    for option in options.chunks(2) {
+        let value = option.get(1).and_then(|value| value.parse::<usize>().ok());
+        match (option[0].as_str(), value) {
+            ("--threads", Some(value)) => threads = value,
+            ("--hash", Some(value)) => hash_mb = Some(value),
+            _ => exit_with_usage_error(&format!("unknown option {}, expected --threads <n> or --hash <mb>", option.join(" "))),
+        }
     }
-    moves.clear();
 
+    let table = match hash_mb.map(PerftTable::new) {
+        None => None,
+        Some(Ok(table)) => Some(table),
+        Some(Err(err)) => exit_with_usage_error(&err.to_string()),
+    };
+    let report = game.parallel_perft(depth, threads, table.as_ref());
+    println!("nodes: {}, time: {} ms, nps: {}", report.nodes, report.elapsed.as_millis(), report.nodes_per_second());
+}
+
+/// Reports a bad command line on stderr and exits with status 2.
+fn exit_with_usage_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(2)
+}
+
+fn play_demo() {
+    let mut game = Game::new();
+
+    println!("{}", game.board());
+    print_moves(&game, "b2");
+
+    play(&mut game, "b2", "b4");
+    play(&mut game, "a7", "a5");
+    println!("\n{}", game.board());
+    print_moves(&game, "b4");
+
+    play(&mut game, "b4", "a5");
+    play(&mut game, "a8", "a5");
+    println!("\n{}", game.board());
+    print_moves(&game, "a1");
+}
+
+fn play(game: &mut Game, from: &str, to: &str) {
+    let (from, to) = (square(from), square(to));
+    let mv = Move::infer(game.board(), &from, &to, None).expect("no piece to move");
+    if let Err(err) = game.play(&mv) {
+        println!("can not play {}{}: {}", from, to, err);
+    }
+}
 
-    board.move_piece(&Position {x: 0, y: 2}, &Position {x: 0, y: 1});
-    println!("\n{}", board);
-    get_moves(&board, &Position {x: 0, y: 0}, &mut moves);
-    for pos in &moves {
-        println!("x: {}, y: {}", pos.x, pos.y);
+fn print_moves(game: &Game, from: &str) {
+    let from = square(from);
+    for mv in game.legal_moves().iter().filter(|mv| mv.from == from) {
+        println!("{}", mv.to);
     }
-    moves.clear();
+}
+
+fn square(name: &str) -> Position {
+    return name.parse().expect("demo squares are valid")
 }
@@ -0,0 +1,42 @@
+use crate::board::{Board, Position};
+use crate::board::bitboard::BitIter;
+use crate::board::bitboard::attack_tables::{bishop_attacks, KING_ATTACKS, KNIGHT_ATTACKS, PAWN_ATTACKS, rook_attacks};
+use crate::board::piece::{Color, PieceCode, PieceState, Type};
+
+impl Board {
+    /// Whether any piece of color `by` attacks `pos`, no matter what stands there.
+    pub fn is_square_attacked(&self, pos: &Position, by: Color) -> bool {
+        return self.attacker_squares(pos, &by).next().is_some()
+    }
+
+    /// Pieces of color `by` attacking `pos`, together with where they stand.
+    pub fn attackers_of(&self, pos: &Position, by: Color) -> Vec<(Position, PieceState)> {
+        return self.attacker_squares(pos, &by)
+            .map(Position::from_board_index)
+            .filter_map(|attacker_pos| self.piece_at(&attacker_pos).map(|state| (attacker_pos, state)))
+            .collect()
+    }
+
+    /// Squares of the pieces of color `by` attacking `pos`. Each kind of piece is looked up in the
+    /// attack tables from `pos` and the squares found are checked against the pieces standing there.
+    fn attacker_squares<'a>(&'a self, pos: &Position, by: &Color) -> impl Iterator<Item = usize> + 'a {
+        let square = pos.as_board_index();
+        let occupied = self.occupied();
+        let straight = rook_attacks(square, occupied);
+        let diagonal = bishop_attacks(square, occupied);
+        let lookups: [(u64, [Type; 2]); 5] = [
+            (PAWN_ATTACKS[by.opposite().index()][square], [Type::Pawn; 2]),
+            (KNIGHT_ATTACKS[square], [Type::Knight; 2]),
+            (KING_ATTACKS[square], [Type::KING; 2]),
+            (straight, [Type::Rook, Type::Queen]),
+            (diagonal, [Type::Bishop, Type::Queen]),
+        ];
+        let by = *by;
+        let own = self.color_set(&by);
+        return lookups.into_iter().flat_map(move |(attacks, types)| {
+            BitIter(attacks & own).filter(move |attacker| {
+                types.iter().any(|piece_type| self.code_at(&Position::from_board_index(*attacker)) == PieceCode::new(*piece_type, by))
+            })
+        })
+    }
+}
@@ -0,0 +1,206 @@
+use crate::board::{Board, BOARD_WIDTH};
+use crate::board::bitboard::{BitBoard, BitIter};
+use crate::board::bitboard::attack_tables::{between, bishop_attacks, KING_ATTACKS, KNIGHT_ATTACKS, PAWN_ATTACKS, rook_attacks};
+use crate::board::piece::{Color, Type};
+use crate::moves::{MoveFlag, MoveList, MoveStage};
+
+/// Where the pieces of the side to move may go without leaving their king attacked.
+struct LegalityMasks {
+    /// Squares which take the checking piece or block its ray, every square when not in check.
+    check_mask: u64,
+    pinned: u64,
+    /// For each pinned piece the ray between the king and the pinning piece, the pinning piece included.
+    pin_rays: [u64; 64],
+}
+
+impl BitBoard {
+    /// Legal moves of every piece of `color`, generated directly from the pinned pieces
+    /// and the squares which answer a check, instead of trying each pseudo-legal move.
+    pub fn get_legal_moves(&self, color: &Color, moves: &mut MoveList) {
+        self.get_legal_stage_moves(color, None, moves);
+    }
+
+    /// Same as [`BitBoard::get_legal_moves`], but only the moves belonging to `stage` when one is given.
+    /// Quiet checks are the quiet moves which leave the enemy king attacked.
+    pub fn get_legal_stage_moves(&self, color: &Color, stage: Option<MoveStage>, moves: &mut MoveList) {
+        if stage != Some(MoveStage::QuietChecks) {
+            self.generate_legal_moves(color, stage, moves);
+            return;
+        }
+
+        let mut quiet_moves = MoveList::new();
+        self.generate_legal_moves(color, Some(MoveStage::Quiets), &mut quiet_moves);
+        for mv in &quiet_moves {
+            let mut board_after_move = self.clone();
+            board_after_move.make_move(mv);
+            if board_after_move.is_in_check(&color.opposite()) {
+                moves.push(mv.clone());
+            }
+        }
+    }
+
+    /// Legal moves of `stage`, where quiet checks count as any quiet move.
+    fn generate_legal_moves(&self, color: &Color, stage: Option<MoveStage>, moves: &mut MoveList) {
+        let king_square = match BitIter(self.pieces(Type::KING, color)).next() {
+            None => return self.get_stage_moves_without_king(color, stage, moves),
+            Some(square) => square,
+        };
+        let own = self.color_set(color);
+        let enemy = self.color_set(&color.opposite());
+        let occupied = own | enemy;
+        let stage_mask = match stage {
+            None => !0,
+            Some(MoveStage::Captures) => enemy,
+            Some(MoveStage::Quiets) | Some(MoveStage::QuietChecks) => !enemy,
+        };
+
+        let checkers = self.attackers_to(king_square, occupied) & enemy;
+        for to in BitIter(KING_ATTACKS[king_square] & !own & stage_mask) {
+            if self.attackers_to(to, occupied ^ (1 << king_square)) & enemy == 0 {
+                self.add_move(king_square, to, MoveFlag::Normal, None, moves);
+            }
+        }
+        if checkers.count_ones() > 1 {
+            return;
+        }
+
+        let masks = self.legality_masks(color, king_square, checkers, occupied);
+        if checkers == 0 && stage != Some(MoveStage::Captures) {
+            self.get_legal_castling(color, king_square, occupied, moves);
+        }
+        self.get_legal_pawn_moves(color, king_square, &masks, stage, moves);
+
+        let targets = !own & masks.check_mask & stage_mask;
+        for from in BitIter(self.pieces(Type::Knight, color) & !masks.pinned) {
+            self.add_moves(from, KNIGHT_ATTACKS[from] & targets, moves);
+        }
+        let bishops = self.pieces(Type::Bishop, color) | self.pieces(Type::Queen, color);
+        for from in BitIter(bishops) {
+            self.add_moves(from, bishop_attacks(from, occupied) & targets & masks.pin_ray(from), moves);
+        }
+        let rooks = self.pieces(Type::Rook, color) | self.pieces(Type::Queen, color);
+        for from in BitIter(rooks) {
+            self.add_moves(from, rook_attacks(from, occupied) & targets & masks.pin_ray(from), moves);
+        }
+    }
+
+    /// Without a king to guard every pseudo-legal move is legal.
+    fn get_stage_moves_without_king(&self, color: &Color, stage: Option<MoveStage>, moves: &mut MoveList) {
+        let mut pseudo_legal_moves = MoveList::new();
+        self.get_moves(color, &mut pseudo_legal_moves);
+        for mv in &pseudo_legal_moves {
+            if stage.is_none_or(|stage| mv.is_capture() == (stage == MoveStage::Captures)) {
+                moves.push(mv.clone());
+            }
+        }
+    }
+
+    /// Pieces of both colors attacking `square` when the board is occupied by `occupied`.
+    #[inline]
+    fn attackers_to(&self, square: usize, occupied: u64) -> u64 {
+        let queens = self.pieces(Type::Queen, &Color::White) | self.pieces(Type::Queen, &Color::Black);
+        let rooks = self.pieces(Type::Rook, &Color::White) | self.pieces(Type::Rook, &Color::Black) | queens;
+        let bishops = self.pieces(Type::Bishop, &Color::White) | self.pieces(Type::Bishop, &Color::Black) | queens;
+        let knights = self.pieces(Type::Knight, &Color::White) | self.pieces(Type::Knight, &Color::Black);
+        let kings = self.pieces(Type::KING, &Color::White) | self.pieces(Type::KING, &Color::Black);
+        return PAWN_ATTACKS[Color::White.index()][square] & self.pieces(Type::Pawn, &Color::Black)
+            | PAWN_ATTACKS[Color::Black.index()][square] & self.pieces(Type::Pawn, &Color::White)
+            | KNIGHT_ATTACKS[square] & knights
+            | KING_ATTACKS[square] & kings
+            | rook_attacks(square, occupied) & rooks
+            | bishop_attacks(square, occupied) & bishops
+    }
+
+    fn legality_masks(&self, color: &Color, king_square: usize, checkers: u64, occupied: u64) -> LegalityMasks {
+        let check_mask = match BitIter(checkers).next() {
+            None => !0,
+            Some(checker) => checkers | between(king_square, checker),
+        };
+
+        let enemy = color.opposite();
+        let enemy_queens = self.pieces(Type::Queen, &enemy);
+        let snipers = rook_attacks(king_square, 0) & (self.pieces(Type::Rook, &enemy) | enemy_queens)
+            | bishop_attacks(king_square, 0) & (self.pieces(Type::Bishop, &enemy) | enemy_queens);
+        let mut masks = LegalityMasks {check_mask, pinned: 0, pin_rays: [0; 64]};
+        for sniper in BitIter(snipers) {
+            let ray = between(king_square, sniper);
+            let blockers = ray & occupied;
+            if blockers.count_ones() == 1 && blockers & self.color_set(color) != 0 {
+                masks.pinned |= blockers;
+                masks.pin_rays[blockers.trailing_zeros() as usize] = ray | (1 << sniper);
+            }
+        }
+        return masks
+    }
+
+    /// Pushes are quiet moves and everything else takes a piece, so `stage` picks one or the other.
+    fn get_legal_pawn_moves(&self, color: &Color, king_square: usize, masks: &LegalityMasks, stage: Option<MoveStage>,
+                            moves: &mut MoveList) {
+        let wants_captures = stage.is_none_or(|stage| stage == MoveStage::Captures);
+        let wants_quiets = stage != Some(MoveStage::Captures);
+        let enemy = self.color_set(&color.opposite());
+        let occupied = self.occupied();
+        let forward = Board::forward(color) * BOARD_WIDTH as isize;
+        let last_row = Board::start_row(&color.opposite());
+        for from in BitIter(self.pieces(Type::Pawn, color)) {
+            let allowed = masks.check_mask & masks.pin_ray(from);
+            let one_step = (from as isize + forward) as usize;
+            if wants_quiets && from / BOARD_WIDTH != last_row && occupied & (1 << one_step) == 0 {
+                self.add_pawn_moves(from, (1 << one_step) & allowed, moves);
+                let two_steps = (one_step as isize + forward) as usize;
+                if from / BOARD_WIDTH == Board::pawn_row(color) && occupied & (1 << two_steps) == 0
+                    && allowed & (1 << two_steps) != 0 {
+                    self.add_move(from, two_steps, MoveFlag::DoublePawnPush, None, moves);
+                }
+            }
+
+            if !wants_captures {
+                continue;
+            }
+            let attacks = PAWN_ATTACKS[color.index()][from];
+            self.add_pawn_moves(from, attacks & enemy & allowed, moves);
+            if let Some(target) = self.en_passant_target() {
+                let to = target.as_board_index();
+                if attacks & (1 << to) != 0 && self.is_legal_en_passant(color, king_square, from, to) {
+                    self.add_move(from, to, MoveFlag::EnPassant, None, moves);
+                }
+            }
+        }
+    }
+
+    /// Taking en passant removes two pawns from the row of the king at once, which can uncover
+    /// an attack no pin covers, so the position after the capture is checked as a whole.
+    fn is_legal_en_passant(&self, color: &Color, king_square: usize, from: usize, to: usize) -> bool {
+        let captured = (to as isize - Board::forward(color) * BOARD_WIDTH as isize) as usize;
+        let occupied = (self.occupied() ^ (1 << from) ^ (1 << captured)) | (1 << to);
+        let enemy = self.color_set(&color.opposite()) & !(1 << captured);
+        return self.attackers_to(king_square, occupied) & enemy == 0
+    }
+
+    fn get_legal_castling(&self, color: &Color, king_square: usize, occupied: u64, moves: &mut MoveList) {
+        let row = Board::start_row(color) * BOARD_WIDTH;
+        if king_square != row + Board::KING_START_X {
+            return;
+        }
+
+        let enemy = self.color_set(&color.opposite());
+        let is_safe = |square: usize| self.attackers_to(square, occupied) & enemy == 0;
+        let rights = self.castling_rights();
+        if rights.king_side(color) && occupied & (0b0110_0000u64 << row) == 0
+            && is_safe(king_square + 1) && is_safe(king_square + 2) {
+            self.add_move(king_square, king_square + 2, MoveFlag::KingSideCastle, None, moves);
+        }
+        if rights.queen_side(color) && occupied & (0b0000_1110u64 << row) == 0
+            && is_safe(king_square - 1) && is_safe(king_square - 2) {
+            self.add_move(king_square, king_square - 2, MoveFlag::QueenSideCastle, None, moves);
+        }
+    }
+}
+
+impl LegalityMasks {
+    /// Squares the piece on `square` may move to as far as pins are concerned.
+    #[inline]
+    fn pin_ray(&self, square: usize) -> u64 {
+        return if self.pinned & (1 << square) != 0 { self.pin_rays[square] } else { !0 }
+    }
+}
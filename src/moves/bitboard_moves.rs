@@ -0,0 +1,130 @@
+use crate::board::{Board, BOARD_WIDTH, Position};
+use crate::board::bitboard::{BitBoard, BitIter};
+use crate::board::bitboard::attack_tables::{bishop_attacks, KING_ATTACKS, KNIGHT_ATTACKS, PAWN_ATTACKS, rook_attacks};
+use crate::board::piece::{Color, Type};
+use crate::moves::{Move, MoveFlag, MoveList};
+
+pub(crate) const LAST_ROWS: u64 = 0xFF000000000000FF;
+
+impl BitBoard {
+    /// Pseudo-legal moves of every piece of `color`, see [`crate::moves::get_moves`].
+    pub fn get_moves(&self, color: &Color, moves: &mut MoveList) {
+        let own = self.color_set(color);
+        let enemy = self.color_set(&color.opposite());
+        let occupied = own | enemy;
+
+        self.get_moves_pawns(color, enemy, occupied, moves);
+        for from in BitIter(self.pieces(Type::Knight, color)) {
+            self.add_moves(from, KNIGHT_ATTACKS[from] & !own, moves);
+        }
+        for from in BitIter(self.pieces(Type::Bishop, color)) {
+            self.add_moves(from, bishop_attacks(from, occupied) & !own, moves);
+        }
+        for from in BitIter(self.pieces(Type::Rook, color)) {
+            self.add_moves(from, rook_attacks(from, occupied) & !own, moves);
+        }
+        for from in BitIter(self.pieces(Type::Queen, color)) {
+            self.add_moves(from, (rook_attacks(from, occupied) | bishop_attacks(from, occupied)) & !own, moves);
+        }
+        for from in BitIter(self.pieces(Type::KING, color)) {
+            self.add_moves(from, KING_ATTACKS[from] & !own, moves);
+            self.get_moves_castling(color, from, occupied, moves);
+        }
+    }
+
+    pub fn is_in_check(&self, color: &Color) -> bool {
+        return match BitIter(self.pieces(Type::KING, color)).next() {
+            None => false,
+            Some(king_square) => self.is_square_attacked(king_square, &color.opposite())
+        }
+    }
+
+    pub fn is_square_attacked(&self, square: usize, by: &Color) -> bool {
+        let occupied = self.occupied();
+        let queens = self.pieces(Type::Queen, by);
+        return PAWN_ATTACKS[by.opposite().index()][square] & self.pieces(Type::Pawn, by) != 0
+            || KNIGHT_ATTACKS[square] & self.pieces(Type::Knight, by) != 0
+            || KING_ATTACKS[square] & self.pieces(Type::KING, by) != 0
+            || rook_attacks(square, occupied) & (self.pieces(Type::Rook, by) | queens) != 0
+            || bishop_attacks(square, occupied) & (self.pieces(Type::Bishop, by) | queens) != 0
+    }
+
+    fn get_moves_pawns(&self, color: &Color, enemy: u64, occupied: u64, moves: &mut MoveList) {
+        let forward = Board::forward(color) * BOARD_WIDTH as isize;
+        let pawn_row = (Board::start_row(color) as isize + Board::forward(color)) as usize;
+        let last_row = Board::start_row(&color.opposite());
+        for from in BitIter(self.pieces(Type::Pawn, color)) {
+            let one_step = (from as isize + forward) as usize;
+            if from / BOARD_WIDTH != last_row && occupied & (1 << one_step) == 0 {
+                self.add_pawn_moves(from, 1 << one_step, moves);
+                let two_steps = (one_step as isize + forward) as usize;
+                if from / BOARD_WIDTH == pawn_row && occupied & (1 << two_steps) == 0 {
+                    self.add_move(from, two_steps, MoveFlag::DoublePawnPush, None, moves);
+                }
+            }
+
+            let attacks = PAWN_ATTACKS[color.index()][from];
+            self.add_pawn_moves(from, attacks & enemy, moves);
+            if let Some(en_passant) = self.en_passant_target() {
+                if attacks & (1 << en_passant.as_board_index()) != 0 {
+                    self.add_move(from, en_passant.as_board_index(), MoveFlag::EnPassant, None, moves);
+                }
+            }
+        }
+    }
+
+    fn get_moves_castling(&self, color: &Color, king_square: usize, occupied: u64, moves: &mut MoveList) {
+        let row = Board::start_row(color) * BOARD_WIDTH;
+        if king_square != row + Board::KING_START_X || self.is_square_attacked(king_square, &color.opposite()) {
+            return;
+        }
+
+        let rights = self.castling_rights();
+        let king_side_between = 0b0110_0000u64 << row;
+        if rights.king_side(color) && occupied & king_side_between == 0
+            && !self.is_square_attacked(king_square + 1, &color.opposite()) {
+            self.add_move(king_square, king_square + 2, MoveFlag::KingSideCastle, None, moves);
+        }
+        let queen_side_between = 0b0000_1110u64 << row;
+        if rights.queen_side(color) && occupied & queen_side_between == 0
+            && !self.is_square_attacked(king_square - 1, &color.opposite()) {
+            self.add_move(king_square, king_square - 2, MoveFlag::QueenSideCastle, None, moves);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn add_pawn_moves(&self, from: usize, targets: u64, moves: &mut MoveList) {
+        for to in BitIter(targets) {
+            if LAST_ROWS & (1 << to) == 0 {
+                self.add_move(from, to, MoveFlag::Normal, None, moves);
+                continue;
+            }
+            for promotion in Type::PROMOTIONS {
+                self.add_move(from, to, MoveFlag::Normal, Some(promotion), moves);
+            }
+        }
+    }
+
+    #[inline]
+    pub(crate) fn add_moves(&self, from: usize, targets: u64, moves: &mut MoveList) {
+        for to in BitIter(targets) {
+            self.add_move(from, to, MoveFlag::Normal, None, moves);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn add_move(&self, from: usize, to: usize, flag: MoveFlag, promotion: Option<Type>, moves: &mut MoveList) {
+        let from = Position::from_board_index(from);
+        let to = Position::from_board_index(to);
+        if let Some(piece) = self.piece_at(&from) {
+            moves.push(Move {
+                captured: self.piece_at(&Move::captured_pos(&from, &to, flag)),
+                from,
+                to,
+                piece,
+                flag,
+                promotion,
+            });
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::board::Position;
+use crate::board::representation::BoardRepresentation;
+use crate::board::piece::{PieceState, Type};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Move {
+    pub from: Position,
+    pub to: Position,
+    pub piece: PieceState,
+    pub captured: Option<PieceState>,
+    pub flag: MoveFlag,
+    pub promotion: Option<Type>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MoveFlag {
+    Normal,
+    DoublePawnPush,
+    EnPassant,
+    KingSideCastle,
+    QueenSideCastle,
+}
+
+impl Move {
+    /// Builds the move of the piece standing at `from`, working out from the board what kind of
+    /// move it is. Returns `None` when there is no piece at `from`.
+    /// The move itself is not validated, use the `moves` module generators for that.
+    pub fn infer<B: BoardRepresentation>(board: &B, from: &Position, to: &Position, promotion: Option<Type>) -> Option<Move> {
+        let piece = board.piece_at(from)?;
+        let flag = match piece.piece_type {
+            Type::KING if from.x.abs_diff(to.x) == 2 && to.x > from.x => MoveFlag::KingSideCastle,
+            Type::KING if from.x.abs_diff(to.x) == 2 => MoveFlag::QueenSideCastle,
+            Type::Pawn if from.y.abs_diff(to.y) == 2 => MoveFlag::DoublePawnPush,
+            Type::Pawn if from.x != to.x && board.is_empty(to) => MoveFlag::EnPassant,
+            _ => MoveFlag::Normal,
+        };
+
+        return Some(Move {
+            from: from.clone(),
+            to: to.clone(),
+            piece,
+            captured: board.piece_at(&Move::captured_pos(from, to, flag)),
+            flag,
+            promotion,
+        })
+    }
+
+    #[inline]
+    pub fn is_capture(&self) -> bool {
+        return self.captured.is_some()
+    }
+
+    #[inline]
+    pub fn is_castling(&self) -> bool {
+        return self.flag == MoveFlag::KingSideCastle || self.flag == MoveFlag::QueenSideCastle
+    }
+
+    /// Square of the captured piece, which differs from `to` only for en passant.
+    #[inline]
+    pub fn captured_pos(from: &Position, to: &Position, flag: MoveFlag) -> Position {
+        return match flag {
+            MoveFlag::EnPassant => Position {x: to.x, y: from.y},
+            _ => to.clone(),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::board::{Board, Position};
+use crate::board::piece::{Color, Type};
+use crate::moves::{get_all_legal_moves, is_in_check, MoveList};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum GameStatus {
+    Ongoing,
+    Check,
+    /// Holds the color of the winning side.
+    Checkmate(Color),
+    Stalemate,
+    Draw(DrawReason),
+    /// The game goes on, but the side to move may claim a draw for the reason given.
+    DrawClaimable(DrawReason),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum DrawReason {
+    /// No capture or pawn move in the last fifty moves of each side, a draw only when claimed.
+    FiftyMoveRule,
+    /// Same as [`DrawReason::FiftyMoveRule`] after seventy-five moves, ends the game on its own.
+    SeventyFiveMoveRule,
+    /// Draw only when claimed.
+    ThreefoldRepetition,
+    FivefoldRepetition,
+    /// Neither side has enough material left to ever checkmate.
+    InsufficientMaterial,
+}
+
+impl GameStatus {
+    #[inline]
+    pub fn is_over(&self) -> bool {
+        return match self {
+            GameStatus::Ongoing | GameStatus::Check | GameStatus::DrawClaimable(..) => false,
+            GameStatus::Checkmate(..) | GameStatus::Stalemate | GameStatus::Draw(..) => true,
+        }
+    }
+}
+
+impl Board {
+    /// Status of the game from the point of view of the side which is about to move.
+    pub fn game_status(&self, color_to_move: &Color) -> GameStatus {
+        let in_check = is_in_check(self, color_to_move);
+        return match (in_check, has_legal_move(self, color_to_move)) {
+            (true, false) => GameStatus::Checkmate(color_to_move.opposite()),
+            (false, false) => GameStatus::Stalemate,
+            _ if self.has_insufficient_material() => GameStatus::Draw(DrawReason::InsufficientMaterial),
+            (false, true) => GameStatus::Ongoing,
+            (true, true) => GameStatus::Check,
+        }
+    }
+
+    /// Dead positions recognised here are a lone king against a king with at most one minor
+    /// piece, and positions where all remaining pieces besides kings are bishops on squares of one color.
+    pub fn has_insufficient_material(&self) -> bool {
+        let mut minor_pieces = Vec::new();
+        for color in [Color::White, Color::Black] {
+            for pos in self.positions_of(&color) {
+                match self.piece_at(&pos).map(|state| state.piece_type) {
+                    Some(Type::KING) => {},
+                    Some(Type::Knight) | Some(Type::Bishop) => minor_pieces.push(pos),
+                    _ => return false,
+                }
+            }
+        }
+
+        if minor_pieces.len() <= 1 {
+            return true
+        }
+        let all_bishops = minor_pieces.iter()
+            .all(|pos| matches!(self.piece_at(pos), Some(state) if state.piece_type == Type::Bishop));
+        let square_color = |pos: &Position| (pos.x + pos.y) % 2;
+        return all_bishops && minor_pieces.iter().all(|pos| square_color(pos) == square_color(&minor_pieces[0]))
+    }
+}
+
+fn has_legal_move(board: &Board, color: &Color) -> bool {
+    let mut moves = MoveList::new();
+    get_all_legal_moves(board, color, &mut moves);
+    return !moves.is_empty()
+}
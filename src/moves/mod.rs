@@ -1,51 +1,129 @@
-use crate::board::{Board, BOARD_SIZE, BOARD_WIDTH, Field, Position};
+use crate::board::{Board, BOARD_WIDTH, Position};
+use crate::board::representation::BoardRepresentation;
+use crate::board::bitboard::BitIter;
+use crate::board::bitboard::attack_tables::{bishop_attacks, rook_attacks};
 use crate::errors::ErrorKind;
-use crate::board::piece::PieceMoved;
 use crate::board::piece::PieceState;
 use crate::board::piece::Type;
 use crate::board::piece::Color;
-use crate::board::piece::Color::{Black, White};
+
+pub use chess_move::{Move, MoveFlag};
+pub use move_list::MoveList;
+pub use staged::{MoveStage, StagedMoves};
+
+pub mod attacks;
+pub mod bitboard_legal_moves;
+pub mod bitboard_moves;
+pub mod chess_move;
+pub mod game_status;
+pub mod move_list;
+pub mod parallel_perft;
+pub mod perft;
+pub mod san;
+pub mod staged;
+pub mod uci;
 
 #[cfg(test)]
 mod tests;
 
-pub fn get_moves(board: &Board, pos: &Position, moves: &mut Vec<Position>){
-    let piece = board.value_at(pos);
-    match piece {
-        Field::Empty => {},
-        Field::Piece(state) => {
-            let mut move_service = PossibleMovesService::new(board, pos, state, moves);
-            move_service.get_piece_moves();
-        }
+/// Step on the board as `(x, y)`.
+pub(crate) type Delta = (isize, isize);
+
+pub(crate) const KNIGHT_DELTAS: [Delta; 8] = [(-2, 1), (-2, -1), (-1, -2), (1, -2), (2, -1), (2, 1), (1, 2), (-1, 2)];
+pub(crate) const KING_DELTAS: [Delta; 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+pub(crate) const STRAIGHT_DIRECTIONS: [Delta; 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+pub(crate) const DIAGONAL_DIRECTIONS: [Delta; 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+pub fn get_moves<B: BoardRepresentation>(board: &B, pos: &Position, moves: &mut MoveList){
+    generate_moves(board, pos, None, moves);
+}
+
+/// Pseudo-legal moves of the piece at `pos`, only those taking a piece or only those
+/// which do not when `stage` is given.
+fn generate_moves<B: BoardRepresentation>(board: &B, pos: &Position, stage: Option<MoveStage>, moves: &mut MoveList) {
+    if let Some(state) = board.piece_at(pos) {
+        let mut move_service = PossibleMovesService::new(board, pos, &state, stage, moves);
+        move_service.get_piece_moves();
+    }
+}
+
+/// Same as [`get_moves`] but drops every move that would leave the mover's own king attacked.
+pub fn get_legal_moves<B: BoardRepresentation>(board: &B, pos: &Position, moves: &mut MoveList) {
+    let color = match board.piece_at(pos) {
+        None => return,
+        Some(state) => state.color,
     };
+
+    let mut legal_moves = MoveList::new();
+    board.legal_moves(&color, None, &mut legal_moves);
+    for mv in &legal_moves {
+        if mv.from == *pos {
+            moves.push(mv.clone());
+        }
+    }
+}
+
+/// Legal moves of every piece of `color`.
+pub fn get_all_legal_moves<B: BoardRepresentation>(board: &B, color: &Color, moves: &mut MoveList) {
+    board.legal_moves(color, None, moves);
 }
 
-struct PossibleMovesService<'s> {
-    moves: &'s mut Vec<Position>,
-    board: &'s Board,
+/// Legal moves of every piece of `color` found by playing each pseudo-legal move and looking
+/// whether the king is attacked afterwards. Much slower than [`get_all_legal_moves`], but simple
+/// enough to check it against.
+pub fn get_all_legal_moves_by_filtering<B: BoardRepresentation>(board: &B, color: &Color, moves: &mut MoveList) {
+    let mut pseudo_legal_moves = MoveList::new();
+    for pos in board.positions_of(color) {
+        get_moves(board, &pos, &mut pseudo_legal_moves);
+    }
+    keep_legal_moves(board, color, &pseudo_legal_moves, moves);
+}
+
+fn keep_legal_moves<B: BoardRepresentation>(board: &B, color: &Color, pseudo_legal_moves: &MoveList, moves: &mut MoveList) {
+    let mut scratch_board = board.clone();
+    for pseudo_legal_move in pseudo_legal_moves {
+        let undo = scratch_board.make_move(pseudo_legal_move);
+        let leaves_king_attacked = is_in_check(&scratch_board, color);
+        scratch_board.unmake_move(pseudo_legal_move, undo);
+        if !leaves_king_attacked {
+            moves.push(pseudo_legal_move.clone());
+        }
+    }
+}
+
+pub fn is_in_check<B: BoardRepresentation>(board: &B, color: &Color) -> bool {
+    return match board.find_king(color) {
+        None => false,
+        Some(king_pos) => board.is_square_attacked(&king_pos, color.opposite())
+    }
+}
+
+struct PossibleMovesService<'s, B: BoardRepresentation> {
+    moves: &'s mut MoveList,
+    board: &'s B,
     piece_pos: &'s Position,
     piece_state: &'s PieceState,
+    stage: Option<MoveStage>,
     opposite_color: Color,
-    forward_y: isize
+    forward_y: isize,
 }
 
-impl PossibleMovesService<'_> {
-    fn new<'s>(board: &'s Board, piece_pos: &'s Position, piece_state: &'s PieceState, vec: &'s mut Vec<Position>) -> PossibleMovesService<'s> {
+impl<B: BoardRepresentation> PossibleMovesService<'_, B> {
+    fn new<'s>(board: &'s B, piece_pos: &'s Position, piece_state: &'s PieceState, stage: Option<MoveStage>,
+               vec: &'s mut MoveList) -> PossibleMovesService<'s, B> {
         return PossibleMovesService{
             moves: vec,
             board,
             piece_pos,
             piece_state,
+            stage,
             opposite_color: piece_state.color.opposite(),
-            forward_y: match piece_state.color {
-                White => Board::WHITE_FORWARD,
-                Black => Board::BLACK_FORWARD,
-            }
+            forward_y: Board::forward(&piece_state.color),
         }
     }
 }
 
-impl PossibleMovesService<'_> {
+impl<B: BoardRepresentation> PossibleMovesService<'_, B> {
     #[inline]
     fn get_piece_moves(&mut self) {
         match self.piece_state.piece_type {
@@ -60,154 +138,166 @@ impl PossibleMovesService<'_> {
 
     #[inline]
     fn get_moves_pawn(&mut self) {
-        let pos_forward = self.piece_pos.delta_y(self.forward_y);
-        if self.board.is_empty(&pos_forward) {
-            self.moves.push(pos_forward);
-            let pos_forward2 = self.piece_pos.delta_y(self.forward_y+ self.forward_y);
-            if self.piece_state.moved == PieceMoved::No && self.board.is_empty(&pos_forward2) {
-                self.moves.push(pos_forward2);
+        let pos_forward = match self.piece_pos.delta_if_valid(0, self.forward_y) {
+            Err(..) => return,
+            Ok(pos) => pos
+        };
+        if self.wants_quiets() && self.board.is_empty(&pos_forward) {
+            self.add_pawn_move(pos_forward);
+            if self.piece_pos.y == Board::pawn_row(&self.piece_state.color) {
+                let pos_forward2 = self.piece_pos.delta(0, 2 * self.forward_y);
+                if self.board.is_empty(&pos_forward2) {
+                    self.add_move(pos_forward2, MoveFlag::DoublePawnPush);
+                }
             }
         }
 
         if self.piece_pos.x > 0 {
-            let left = self.piece_pos.delta(-1, self.forward_y);
-            if self.board.is_color(&left, &self.opposite_color) {
-                self.moves.push(left)
-            }
+            self.add_pawn_capture_if_possible(-1);
         }
 
         if self.piece_pos.x < BOARD_WIDTH - 1 {
-            let right = self.piece_pos.delta(1, self.forward_y);
-            if self.board.is_color(&right, &self.opposite_color) {
-                self.moves.push(right)
-            }
+            self.add_pawn_capture_if_possible(1);
         }
     }
 
     #[inline]
-    fn get_moves_king(&mut self) {
-        let pos = self.piece_pos;
-        if pos.x > 0 {
-            let left_pos = pos.delta_x(-1);
-            self.add_pos_if_empty_or_enemy(left_pos);
+    fn add_pawn_capture_if_possible(&mut self, delta_x: isize) {
+        let target = self.piece_pos.delta(delta_x, self.forward_y);
+        if self.board.is_color(&target, &self.opposite_color) {
+            self.add_pawn_move(target)
+        } else if self.board.en_passant_target().as_ref() == Some(&target) {
+            self.add_move(target, MoveFlag::EnPassant)
+        }
+    }
 
-            if pos.y > 0 {
-                let left_bottom_pos = pos.delta(-1, -1);
-                self.add_pos_if_empty_or_enemy(left_bottom_pos);
+    /// Pawn reaching the last row gets one move for every piece it can be promoted to.
+    #[inline]
+    fn add_pawn_move(&mut self, to: Position) {
+        if to.y != Board::start_row(&self.opposite_color) {
+            self.add_move(to, MoveFlag::Normal);
+            return;
+        }
 
-                let bottom_pos = pos.delta_y(-1);
-                self.add_pos_if_empty_or_enemy(bottom_pos);
-            }
+        for promotion in Type::PROMOTIONS {
+            self.add_move_with_promotion(to.clone(), MoveFlag::Normal, Some(promotion));
+        }
+    }
 
-            if pos.y < BOARD_WIDTH {
-                let left_top_pos = pos.delta(-1, 1);
-                self.add_pos_if_empty_or_enemy(left_top_pos);
+    #[inline]
+    fn get_moves_king(&mut self) {
+        for (delta_x, delta_y) in KING_DELTAS {
+            self.try_add_pos_if_empty_or_enemy(self.piece_pos.delta_if_valid(delta_x, delta_y));
+        }
+        if self.wants_quiets() {
+            self.get_moves_castling();
+        }
+    }
 
-                let top_pos = pos.delta_y(1);
-                self.add_pos_if_empty_or_enemy(top_pos);
-            }
+    fn get_moves_castling(&mut self) {
+        let color = self.piece_state.color;
+        let row = Board::start_row(&color);
+        if self.piece_pos != &(Position {x: Board::KING_START_X, y: row})
+            || is_in_check(self.board, &color) {
+            return;
         }
 
-        if pos.x < BOARD_SIZE {
-            let right = pos.delta_x(1);
-            self.add_pos_if_empty_or_enemy(right);
+        let rights = self.board.castling_rights();
+        if rights.king_side(&color) && self.can_castle(&[5, 6], 5) {
+            self.add_move(Position {x: 6, y: row}, MoveFlag::KingSideCastle);
+        }
+        if rights.queen_side(&color) && self.can_castle(&[1, 2, 3], 3) {
+            self.add_move(Position {x: 2, y: row}, MoveFlag::QueenSideCastle);
+        }
+    }
 
-            if pos.y > 0 {
-                let right_bottom_pos = pos.delta(1, -1);
-                self.add_pos_if_empty_or_enemy(right_bottom_pos);
-            }
-            if pos.y < BOARD_SIZE {
-                let right_bottom_pos = pos.delta(1, 1);
-                self.add_pos_if_empty_or_enemy(right_bottom_pos);
-            }
+    /// Checks the squares between the rook and the king, and that the square the king passes
+    /// through is not attacked. The destination square is checked like any other king move
+    /// when filtering legal moves.
+    fn can_castle(&self, empty_xs: &[usize], passed_x: usize) -> bool {
+        let row = self.piece_pos.y;
+        if !empty_xs.iter().all(|x| self.board.is_empty(&Position {x: *x, y: row})) {
+            return false;
         }
+
+        return !self.board.is_square_attacked(&Position {x: passed_x, y: row}, self.opposite_color)
     }
 
     #[inline]
     fn get_moves_rook(&mut self) {
-        self.get_moves_horizontal();
-        self.get_moves_vertical();
+        self.add_slider_moves(rook_attacks(self.piece_pos.as_board_index(), self.occupied()));
     }
 
     #[inline]
     fn get_moves_knight(&mut self) {
-        let pos = self.piece_pos;
-        self.try_add_pos_if_empty_or_enemy(pos.delta_if_valid(-2, 1));
-        self.try_add_pos_if_empty_or_enemy(pos.delta_if_valid(-2, -1));
-        self.try_add_pos_if_empty_or_enemy(pos.delta_if_valid(-1, -2));
-        self.try_add_pos_if_empty_or_enemy(pos.delta_if_valid(1, -2));
-        self.try_add_pos_if_empty_or_enemy(pos.delta_if_valid(2, -1));
-        self.try_add_pos_if_empty_or_enemy(pos.delta_if_valid(2, 1));
-        self.try_add_pos_if_empty_or_enemy(pos.delta_if_valid(1, 2));
-        self.try_add_pos_if_empty_or_enemy(pos.delta_if_valid(-1, 2));
+        for (delta_x, delta_y) in KNIGHT_DELTAS {
+            self.try_add_pos_if_empty_or_enemy(self.piece_pos.delta_if_valid(delta_x, delta_y));
+        }
     }
 
     #[inline]
     fn get_moves_bishop(&mut self) {
-        self.get_moves_diagonal();
+        self.add_slider_moves(bishop_attacks(self.piece_pos.as_board_index(), self.occupied()));
     }
 
     #[inline]
     fn get_moves_queen(&mut self) {
-        self.get_moves_knight();
-        self.get_moves_bishop();
+        let square = self.piece_pos.as_board_index();
+        let occupied = self.occupied();
+        self.add_slider_moves(rook_attacks(square, occupied) | bishop_attacks(square, occupied));
     }
 
+    /// Adds a move to every square in `attacks` not taken by a piece of the mover's own color.
     #[inline]
-    fn get_moves_vertical(&mut self) {
-        self.get_moves_loop(0, 1);
-        self.get_moves_loop(0, -1);
+    fn add_slider_moves(&mut self, attacks: u64) {
+        for square in BitIter(attacks & !self.board.color_set(&self.piece_state.color)) {
+            self.add_move(Position::from_board_index(square), MoveFlag::Normal);
+        }
     }
 
     #[inline]
-    fn get_moves_horizontal(&mut self) {
-        self.get_moves_loop(1, 0);
-        self.get_moves_loop(-1, 0);
+    fn occupied(&self) -> u64 {
+        return self.board.color_set(&Color::White) | self.board.color_set(&Color::Black)
     }
+}
 
+impl<B: BoardRepresentation> PossibleMovesService<'_, B> {
     #[inline]
-    fn get_moves_diagonal(&mut self) {
-        self.get_moves_loop(-1, -1);
-        self.get_moves_loop(-1, 1);
-        self.get_moves_loop(1, -1);
-        self.get_moves_loop(1, 1);
+    fn add_move(&mut self, to: Position, flag: MoveFlag) {
+        self.add_move_with_promotion(to, flag, None);
     }
 
     #[inline]
-    fn get_moves_loop(&mut self,
-                      delta_x: isize,
-                      delta_y: isize,
-    ) {
-        let mut previous_pos = self.piece_pos.clone();
-        let mut new_pos: Position;
-        let mut new_pos_result: Result<Position, ErrorKind>;
-
-        loop {
-            new_pos_result = previous_pos.delta_if_valid(delta_x, delta_y);
-            match new_pos_result {
-                Err(..) => break,
-                Ok(pos) => new_pos = pos
-            }
-
-            if self.board.is_empty(&new_pos) {
-                self.moves.push(new_pos.clone());
-                previous_pos = new_pos;
-                continue;
-            }
+    fn wants_quiets(&self) -> bool {
+        return self.stage != Some(MoveStage::Captures)
+    }
 
-            if self.board.is_color(&new_pos, &self.opposite_color) {
-                self.moves.push(new_pos);
-            }
-            break;
+    #[inline]
+    fn add_move_with_promotion(&mut self, to: Position, flag: MoveFlag, promotion: Option<Type>) {
+        let captured = self.board.piece_at(&Move::captured_pos(self.piece_pos, &to, flag));
+        let wanted = match self.stage {
+            None => true,
+            Some(MoveStage::Captures) => captured.is_some(),
+            Some(MoveStage::Quiets) | Some(MoveStage::QuietChecks) => captured.is_none(),
+        };
+        if !wanted {
+            return;
         }
+
+        self.moves.push(Move {
+            from: self.piece_pos.clone(),
+            captured,
+            to,
+            piece: *self.piece_state,
+            flag,
+            promotion,
+        });
     }
-}
 
-impl PossibleMovesService<'_> {
     #[inline]
     fn add_pos_if_empty_or_enemy(&mut self, pos: Position) {
         if self.board.is_empty_or_color(&pos, &self.opposite_color) {
-            self.moves.push(pos);
+            self.add_move(pos, MoveFlag::Normal);
         }
     }
 
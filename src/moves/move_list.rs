@@ -0,0 +1,88 @@
+use std::{fmt, slice};
+use std::fmt::{Debug, Formatter};
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use crate::moves::Move;
+
+// Moves past `len` are never read nor dropped, which is only fine while there is nothing to drop.
+const _: () = assert!(!std::mem::needs_drop::<Move>());
+
+/// Moves kept in place on the stack, so that generating them does not allocate.
+/// Behaves like a slice of the moves pushed so far, which also gives iteration and sorting.
+/// The unused part is left uninitialized, so a new list costs nothing to set up.
+pub struct MoveList {
+    moves: [MaybeUninit<Move>; MoveList::CAPACITY],
+    len: usize,
+}
+
+impl MoveList {
+    /// More than the most moves possible in any position.
+    pub const CAPACITY: usize = 256;
+
+    #[inline]
+    pub fn new() -> MoveList {
+        return MoveList {moves: [const { MaybeUninit::uninit() }; MoveList::CAPACITY], len: 0}
+    }
+
+    /// Panics when the list is full.
+    #[inline]
+    pub fn push(&mut self, mv: Move) {
+        self.moves[self.len].write(mv);
+        self.len += 1;
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl Clone for MoveList {
+    fn clone(&self) -> Self {
+        let mut moves = MoveList::new();
+        for mv in self.iter() {
+            moves.push(mv.clone());
+        }
+        return moves
+    }
+}
+
+impl Default for MoveList {
+    fn default() -> Self {
+        return MoveList::new()
+    }
+}
+
+impl Deref for MoveList {
+    type Target = [Move];
+
+    #[inline]
+    fn deref(&self) -> &[Move] {
+        // The first `len` moves were written by `push`.
+        return unsafe { slice::from_raw_parts(self.moves.as_ptr().cast::<Move>(), self.len) }
+    }
+}
+
+impl DerefMut for MoveList {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [Move] {
+        // The first `len` moves were written by `push`.
+        return unsafe { slice::from_raw_parts_mut(self.moves.as_mut_ptr().cast::<Move>(), self.len) }
+    }
+}
+
+impl<'a> IntoIterator for &'a MoveList {
+    type Item = &'a Move;
+    type IntoIter = std::slice::Iter<'a, Move>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter()
+    }
+}
+
+impl Debug for MoveList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return f.debug_list().entries(self.iter()).finish()
+    }
+}
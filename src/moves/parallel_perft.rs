@@ -0,0 +1,94 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::board::Board;
+use crate::board::piece::Color;
+use crate::errors::ErrorKind;
+use crate::moves::{get_all_legal_moves, MoveList};
+use crate::moves::perft::count_nodes;
+
+/// Node counts of subtrees already counted, shared by all threads of [`parallel_perft`] without locks.
+/// Each entry keeps the key xor-ed with the data, so that an entry torn by two threads writing at once
+/// is not recognised and simply counted again.
+pub struct PerftTable {
+    entries: Vec<[AtomicU64; 2]>,
+}
+
+impl PerftTable {
+    const ENTRY_SIZE: usize = 16;
+    const DEPTH_BITS: u32 = 8;
+
+    /// Table taking up `size_mb` megabytes, fails when that much can not be addressed or allocated.
+    pub fn new(size_mb: usize) -> Result<PerftTable, ErrorKind> {
+        let size = size_mb.checked_mul(1024 * 1024).ok_or(ErrorKind::PerftTableTooLarge)?;
+        let len = (size / PerftTable::ENTRY_SIZE).max(1);
+        let mut entries = Vec::new();
+        entries.try_reserve_exact(len).map_err(|_| ErrorKind::PerftTableTooLarge)?;
+        entries.extend((0..len).map(|_| [AtomicU64::new(0), AtomicU64::new(0)]));
+        return Ok(PerftTable {entries})
+    }
+
+    #[inline]
+    pub(crate) fn probe(&self, key: u64, depth: usize) -> Option<u64> {
+        let entry = &self.entries[(key % self.entries.len() as u64) as usize];
+        let data = entry[1].load(Ordering::Relaxed);
+        let is_match = entry[0].load(Ordering::Relaxed) ^ data == key
+            && data & ((1 << PerftTable::DEPTH_BITS) - 1) == depth as u64;
+        return if is_match { Some(data >> PerftTable::DEPTH_BITS) } else { None }
+    }
+
+    #[inline]
+    pub(crate) fn store(&self, key: u64, depth: usize, nodes: u64) {
+        let entry = &self.entries[(key % self.entries.len() as u64) as usize];
+        let data = nodes << PerftTable::DEPTH_BITS | depth as u64;
+        entry[0].store(key ^ data, Ordering::Relaxed);
+        entry[1].store(data, Ordering::Relaxed);
+    }
+}
+
+pub struct PerftReport {
+    pub nodes: u64,
+    pub elapsed: Duration,
+}
+
+impl PerftReport {
+    pub fn nodes_per_second(&self) -> u64 {
+        let seconds = self.elapsed.as_secs_f64();
+        return if seconds > 0.0 { (self.nodes as f64 / seconds) as u64 } else { 0 }
+    }
+}
+
+/// Same count as [`crate::moves::perft::perft`], with the moves of the root position handed out
+/// to `threads` threads one at a time. Subtree counts are kept in `table` when one is given.
+pub fn parallel_perft(board: &Board, color: &Color, depth: usize, threads: usize, table: Option<&PerftTable>) -> PerftReport {
+    let start = Instant::now();
+    if depth <= 1 {
+        let nodes = count_nodes(&mut board.clone(), color, depth, table);
+        return PerftReport {nodes, elapsed: start.elapsed()}
+    }
+
+    let mut root_moves = MoveList::new();
+    get_all_legal_moves(board, color, &mut root_moves);
+    let next_move = AtomicUsize::new(0);
+    let nodes = AtomicU64::new(0);
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| {
+                let mut scratch_board = board.clone();
+                loop {
+                    let index = next_move.fetch_add(1, Ordering::Relaxed);
+                    let mv = match root_moves.get(index) {
+                        None => break,
+                        Some(mv) => mv,
+                    };
+                    let undo = scratch_board.make_move(mv);
+                    let subtree_nodes = count_nodes(&mut scratch_board, &color.opposite(), depth - 1, table);
+                    scratch_board.unmake_move(mv, undo);
+                    nodes.fetch_add(subtree_nodes, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+
+    return PerftReport {nodes: nodes.into_inner(), elapsed: start.elapsed()}
+}
@@ -0,0 +1,62 @@
+use crate::board::representation::BoardRepresentation;
+use crate::board::piece::Color;
+use crate::moves::{get_all_legal_moves, Move, MoveList};
+use crate::moves::parallel_perft::PerftTable;
+
+/// Number of positions reached after playing every sequence of `depth` legal moves,
+/// starting with `color` to move.
+pub fn perft<B: BoardRepresentation>(board: &B, color: &Color, depth: usize) -> u64 {
+    let mut scratch_board = board.clone();
+    return count_nodes(&mut scratch_board, color, depth, None)
+}
+
+/// [`perft`] split by the first move, in the order the moves are generated.
+/// Empty at depth 0, where no move is played.
+pub fn divide<B: BoardRepresentation>(board: &B, color: &Color, depth: usize) -> Vec<(Move, u64)> {
+    if depth == 0 {
+        return Vec::new()
+    }
+
+    let mut moves = MoveList::new();
+    get_all_legal_moves(board, color, &mut moves);
+
+    let mut scratch_board = board.clone();
+    let mut counts = Vec::with_capacity(moves.len());
+    for mv in &moves {
+        let undo = scratch_board.make_move(mv);
+        let nodes = count_nodes(&mut scratch_board, &color.opposite(), depth - 1, None);
+        scratch_board.unmake_move(mv, undo);
+        counts.push((mv.clone(), nodes));
+    }
+    return counts
+}
+
+/// The recursion behind [`perft`], looking subtree counts up in `table` before generating
+/// any moves and keeping them there when one is given.
+pub(crate) fn count_nodes<B: BoardRepresentation>(board: &mut B, color: &Color, depth: usize, table: Option<&PerftTable>) -> u64 {
+    if depth == 0 {
+        return 1
+    }
+
+    let table_entry = table.map(|table| (table, board.zobrist_key(color)));
+    if let Some(nodes) = table_entry.and_then(|(table, key)| table.probe(key, depth)) {
+        return nodes
+    }
+
+    let mut moves = MoveList::new();
+    get_all_legal_moves(board, color, &mut moves);
+    let mut nodes = moves.len() as u64;
+    if depth > 1 {
+        nodes = 0;
+        for mv in &moves {
+            let undo = board.make_move(mv);
+            nodes += count_nodes(board, &color.opposite(), depth - 1, table);
+            board.unmake_move(mv, undo);
+        }
+    }
+
+    if let Some((table, key)) = table_entry {
+        table.store(key, depth, nodes);
+    }
+    return nodes
+}
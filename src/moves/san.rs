@@ -0,0 +1,141 @@
+use crate::board::Position;
+use crate::board::representation::BoardRepresentation;
+use crate::board::piece::{Color, Type};
+use crate::errors::ErrorKind;
+use crate::moves::{get_all_legal_moves, is_in_check, Move, MoveFlag, MoveList};
+
+/// Standard Algebraic Notation of the legal move `mv`, like "Nbd7", "exd6", "O-O-O" or "e8=Q+".
+/// The moving piece is only told apart by file, rank or both when another piece of its kind
+/// could legally move to the same square.
+pub fn to_san<B: BoardRepresentation>(board: &B, mv: &Move) -> String {
+    let mut san = match mv.flag {
+        MoveFlag::KingSideCastle => "O-O".to_string(),
+        MoveFlag::QueenSideCastle => "O-O-O".to_string(),
+        _ => piece_part(board, mv),
+    };
+
+    let opponent = mv.piece.color.opposite();
+    let mut scratch_board = board.clone();
+    scratch_board.make_move(mv);
+    if is_in_check(&scratch_board, &opponent) {
+        let mut replies = MoveList::new();
+        get_all_legal_moves(&scratch_board, &opponent, &mut replies);
+        san.push(if replies.is_empty() { '#' } else { '+' });
+    }
+    return san
+}
+
+/// The legal move of `color` written as `san`. Check and annotation suffixes like "+", "#", "!?"
+/// and " e.p." are accepted but not required, as are "0-0" castling and promotions without "=".
+pub fn parse_san<B: BoardRepresentation>(board: &B, color: &Color, san: &str) -> Result<Move, ErrorKind> {
+    let mut legal_moves = MoveList::new();
+    get_all_legal_moves(board, color, &mut legal_moves);
+
+    let san = san.trim().trim_end_matches("e.p.").trim_end().trim_end_matches(['+', '#', '!', '?']);
+    let castling = match san {
+        "O-O" | "0-0" => Some(MoveFlag::KingSideCastle),
+        "O-O-O" | "0-0-0" => Some(MoveFlag::QueenSideCastle),
+        _ => None,
+    };
+    if let Some(flag) = castling {
+        return legal_moves.iter().find(|mv| mv.flag == flag).cloned().ok_or(ErrorKind::IllegalMove)
+    }
+
+    let pattern = SanPattern::parse(san).ok_or(ErrorKind::InvalidSan)?;
+    let mut candidates = legal_moves.iter().filter(|mv| pattern.matches(mv));
+    return match (candidates.next(), candidates.next()) {
+        (Some(mv), None) => Ok(mv.clone()),
+        (None, _) => Err(ErrorKind::IllegalMove),
+        (Some(_), Some(_)) => Err(ErrorKind::AmbiguousMove),
+    }
+}
+
+fn piece_part<B: BoardRepresentation>(board: &B, mv: &Move) -> String {
+    let mut san = String::new();
+    if mv.piece.piece_type == Type::Pawn {
+        if mv.is_capture() {
+            san.push(mv.from.file());
+        }
+    } else {
+        san.push(mv.piece.piece_type.letter());
+        san.push_str(&disambiguation(board, mv));
+    }
+    if mv.is_capture() {
+        san.push('x');
+    }
+    san.push_str(&mv.to.to_string());
+    if let Some(promotion) = mv.promotion {
+        san.push('=');
+        san.push(promotion.letter());
+    }
+    return san
+}
+
+fn disambiguation<B: BoardRepresentation>(board: &B, mv: &Move) -> String {
+    let mut legal_moves = MoveList::new();
+    get_all_legal_moves(board, &mv.piece.color, &mut legal_moves);
+    let rivals: Vec<&Position> = legal_moves.iter()
+        .filter(|other| other.piece.piece_type == mv.piece.piece_type && other.to == mv.to && other.from != mv.from)
+        .map(|other| &other.from)
+        .collect();
+
+    return if rivals.is_empty() {
+        String::new()
+    } else if rivals.iter().all(|from| from.x != mv.from.x) {
+        mv.from.file().to_string()
+    } else if rivals.iter().all(|from| from.y != mv.from.y) {
+        mv.from.rank().to_string()
+    } else {
+        mv.from.to_string()
+    }
+}
+
+/// What a SAN move other than castling tells about the move it stands for.
+struct SanPattern {
+    piece_type: Type,
+    from_x: Option<usize>,
+    from_y: Option<usize>,
+    to: Position,
+    promotion: Option<Type>,
+}
+
+impl SanPattern {
+    fn parse(san: &str) -> Option<SanPattern> {
+        let (san, promotion) = match san.char_indices().rev().nth(1) {
+            Some((index, '=')) => (&san[..index], Some(Type::from_letter(san[index + 1..].chars().next()?)?)),
+            Some((index, '1' | '8')) if san.ends_with(['Q', 'R', 'B', 'N']) =>
+                (&san[..index + 1], Type::from_letter(san[index + 1..].chars().next()?)),
+            _ => (san, None),
+        };
+        if promotion.is_some_and(|promotion| !Type::PROMOTIONS.contains(&promotion)) || !san.is_ascii() || san.len() < 2 {
+            return None
+        }
+
+        let (piece_type, san) = match san.chars().next()? {
+            letter @ ('K' | 'Q' | 'R' | 'B' | 'N') => (Type::from_letter(letter)?, &san[1..]),
+            _ => (Type::Pawn, san),
+        };
+        let to: Position = san.get(san.len().checked_sub(2)?..)?.parse().ok()?;
+        let from_part = san[..san.len() - 2].trim_end_matches('x');
+        let mut from_x = None;
+        let mut from_y = None;
+        for symbol in from_part.chars() {
+            match symbol {
+                'a'..='h' if from_x.is_none() && from_y.is_none() => from_x = Some(symbol as usize - 'a' as usize),
+                '1'..='8' if from_y.is_none() => from_y = Some(symbol as usize - '1' as usize),
+                _ => return None,
+            }
+        }
+        return Some(SanPattern {piece_type, from_x, from_y, to, promotion})
+    }
+
+    fn matches(&self, mv: &Move) -> bool {
+        return !mv.is_castling()
+            && mv.piece.piece_type == self.piece_type
+            && mv.to == self.to
+            && mv.promotion == self.promotion
+            && (self.piece_type != Type::Pawn || self.from_x.is_some() || !mv.is_capture())
+            && self.from_x.is_none_or(|x| x == mv.from.x)
+            && self.from_y.is_none_or(|y| y == mv.from.y)
+    }
+}
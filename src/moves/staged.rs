@@ -0,0 +1,76 @@
+use crate::board::Position;
+use crate::board::piece::Color;
+use crate::board::representation::BoardRepresentation;
+use crate::moves::{generate_moves, is_in_check, MoveList};
+
+/// Part of the moves of a position, so that a search can look at captures first
+/// and stop before generating the rest.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MoveStage {
+    /// Moves taking a piece, en passant included.
+    Captures,
+    /// Moves which do not take a piece, promotions and castling included.
+    Quiets,
+    /// Moves which do not take a piece but give check, for quiescence search.
+    QuietChecks,
+}
+
+impl MoveStage {
+    /// Stages which together give every legal move once.
+    pub const ALL_MOVES: [MoveStage; 2] = [MoveStage::Captures, MoveStage::Quiets];
+    pub const QUIESCENCE: [MoveStage; 2] = [MoveStage::Captures, MoveStage::QuietChecks];
+}
+
+/// Pseudo-legal moves of the piece at `pos` which belong to `stage`.
+pub fn get_stage_moves<B: BoardRepresentation>(board: &B, pos: &Position, stage: MoveStage, moves: &mut MoveList) {
+    if stage != MoveStage::QuietChecks {
+        generate_moves(board, pos, Some(stage), moves);
+        return;
+    }
+
+    let color = match board.piece_at(pos) {
+        None => return,
+        Some(state) => state.color,
+    };
+    let mut quiet_moves = MoveList::new();
+    generate_moves(board, pos, Some(MoveStage::Quiets), &mut quiet_moves);
+    let mut scratch_board = board.clone();
+    for mv in &quiet_moves {
+        let undo = scratch_board.make_move(mv);
+        let gives_check = is_in_check(&scratch_board, &color.opposite());
+        scratch_board.unmake_move(mv, undo);
+        if gives_check {
+            moves.push(mv.clone());
+        }
+    }
+}
+
+/// Legal moves of every piece of `color` which belong to `stage`.
+pub fn get_all_legal_stage_moves<B: BoardRepresentation>(board: &B, color: &Color, stage: MoveStage, moves: &mut MoveList) {
+    board.legal_moves(color, Some(stage), moves);
+}
+
+/// Legal moves of a position one stage after another, each stage generated only when asked for.
+pub struct StagedMoves<'b, B: BoardRepresentation> {
+    board: &'b B,
+    color: Color,
+    stages: &'b [MoveStage],
+}
+
+impl<'b, B: BoardRepresentation> StagedMoves<'b, B> {
+    pub fn new(board: &'b B, color: Color, stages: &'b [MoveStage]) -> StagedMoves<'b, B> {
+        return StagedMoves {board, color, stages}
+    }
+}
+
+impl<B: BoardRepresentation> Iterator for StagedMoves<'_, B> {
+    type Item = (MoveStage, MoveList);
+
+    fn next(&mut self) -> Option<(MoveStage, MoveList)> {
+        let (stage, rest) = self.stages.split_first()?;
+        self.stages = rest;
+        let mut moves = MoveList::new();
+        get_all_legal_stage_moves(self.board, &self.color, *stage, &mut moves);
+        return Some((*stage, moves))
+    }
+}
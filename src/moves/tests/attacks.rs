@@ -0,0 +1,60 @@
+use crate::board::{Board, Field, Position};
+use crate::board::piece::Color::{Black, White};
+use crate::board::piece::Type::{Bishop, KING, Knight, Pawn, Queen, Rook};
+
+#[test]
+fn test_pawn_attacks_empty_squares_diagonally() {
+    let board = Board::new_from(vec![
+        (Field::new_white(Pawn), Position {x: 3, y: 3}),
+        (Field::new_black(Pawn), Position {x: 6, y: 6}),
+    ]);
+
+    assert!(board.is_square_attacked(&Position {x: 2, y: 4}, White));
+    assert!(board.is_square_attacked(&Position {x: 4, y: 4}, White));
+    assert!(!board.is_square_attacked(&Position {x: 3, y: 4}, White));
+    assert!(!board.is_square_attacked(&Position {x: 2, y: 2}, White));
+    assert!(board.is_square_attacked(&Position {x: 5, y: 5}, Black));
+    assert!(board.is_square_attacked(&Position {x: 7, y: 5}, Black));
+    assert!(!board.is_square_attacked(&Position {x: 5, y: 7}, Black));
+}
+
+#[test]
+fn test_sliders_are_blocked() {
+    let board = Board::new_from(vec![
+        (Field::new_black(Rook), Position {x: 0, y: 4}),
+        (Field::new_black(Bishop), Position {x: 7, y: 7}),
+        (Field::new_white(Knight), Position {x: 5, y: 5}),
+    ]);
+
+    assert!(board.is_square_attacked(&Position {x: 7, y: 4}, Black));
+    assert!(board.is_square_attacked(&Position {x: 5, y: 5}, Black));
+    assert!(!board.is_square_attacked(&Position {x: 3, y: 3}, Black));
+    assert!(!board.is_square_attacked(&Position {x: 0, y: 4}, Black));
+}
+
+#[test]
+fn test_attackers_of() {
+    let target = Position {x: 4, y: 4};
+    let board = Board::new_from(vec![
+        (Field::new_white(Queen), Position {x: 1, y: 1}),
+        (Field::new_white(Rook), Position {x: 4, y: 0}),
+        (Field::new_white(Knight), Position {x: 5, y: 2}),
+        (Field::new_white(KING), Position {x: 5, y: 5}),
+        (Field::new_white(Pawn), Position {x: 3, y: 3}),
+        (Field::new_white(Bishop), Position {x: 6, y: 7}),
+        (Field::new_black(Rook), Position {x: 4, y: 7}),
+    ]);
+
+    let attackers = board.attackers_of(&target, White);
+    let mut attacker_positions: Vec<Position> = attackers.iter().map(|(pos, _)| pos.clone()).collect();
+    attacker_positions.sort_by_key(|pos| (pos.y, pos.x));
+    assert_eq!(vec![
+        Position {x: 4, y: 0},
+        Position {x: 5, y: 2},
+        Position {x: 3, y: 3},
+        Position {x: 5, y: 5},
+    ], attacker_positions);
+
+    assert_eq!(vec![(Position {x: 4, y: 7}, board.piece_at(&Position {x: 4, y: 7}).unwrap())],
+               board.attackers_of(&target, Black));
+}
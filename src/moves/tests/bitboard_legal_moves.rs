@@ -0,0 +1,89 @@
+use crate::board::Board;
+use crate::board::bitboard::BitBoard;
+use crate::board::piece::Color;
+use crate::board::piece::Type::{KING, Knight, Rook};
+use crate::moves;
+use crate::moves::{MoveFlag, MoveList};
+use crate::moves::tests::utils;
+
+fn perft(bit_board: &BitBoard, color: &Color, depth: usize) -> u64 {
+    let mut moves = MoveList::new();
+    bit_board.get_legal_moves(color, &mut moves);
+    if depth == 1 {
+        return moves.len() as u64
+    }
+    return moves.iter()
+        .map(|mv| {
+            let mut board_after_move = bit_board.clone();
+            board_after_move.make_move(mv);
+            perft(&board_after_move, &color.opposite(), depth - 1)
+        })
+        .sum()
+}
+
+fn legal_moves(fen: &str) -> MoveList {
+    let (board, color) = Board::from_fen(fen).unwrap();
+    let mut moves = MoveList::new();
+    BitBoard::from_board(&board).get_legal_moves(&color, &mut moves);
+
+    let mut expected = MoveList::new();
+    moves::get_all_legal_moves_by_filtering(&board, &color, &mut expected);
+    assert_eq!(utils::move_keys(&expected), utils::move_keys(&moves));
+    return moves
+}
+
+#[test]
+fn test_perft_reference_positions() {
+    let positions: [(&str, u64); 6] = [
+        ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -", 8902),
+        ("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -", 97862),
+        ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -", 2812),
+        ("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1", 9467),
+        ("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8", 62379),
+        ("r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10", 89890),
+    ];
+    for (fen, nodes) in positions {
+        let (board, color) = Board::from_fen(fen).unwrap();
+        assert_eq!(nodes, perft(&BitBoard::from_board(&board), &color, 3), "{}", fen);
+    }
+    let (board, color) = Board::from_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -").unwrap();
+    assert_eq!(674624, perft(&BitBoard::from_board(&board), &color, 5));
+}
+
+#[test]
+fn test_double_check_allows_only_king_moves() {
+    let moves = legal_moves("4r2k/8/8/8/8/3n4/8/3QK3 w - -");
+    assert!(!moves.is_empty());
+    assert!(moves.iter().all(|mv| mv.piece.piece_type == KING));
+}
+
+#[test]
+fn test_check_is_blocked_or_checker_taken() {
+    let moves = legal_moves("4r1k1/8/8/8/8/8/1B6/R3K3 w Q -");
+    assert!(moves.iter().all(|mv| mv.piece.piece_type == KING || mv.to.x == 4));
+    assert!(!moves.iter().any(|mv| mv.is_castling()));
+}
+
+#[test]
+fn test_pinned_pieces_move_along_pin() {
+    let moves = legal_moves("4k3/4r3/8/8/1b6/4R3/3N4/4K3 w - -");
+    assert!(!moves.iter().any(|mv| mv.piece.piece_type == Knight));
+    let rook_moves: Vec<_> = moves.iter().filter(|mv| mv.piece.piece_type == Rook).collect();
+    assert_eq!(5, rook_moves.len());
+    assert!(rook_moves.iter().all(|mv| mv.to.x == 4));
+}
+
+#[test]
+fn test_en_passant_can_not_uncover_attack_along_row() {
+    let moves = legal_moves("8/8/8/KPp4r/8/8/8/7k w - c6");
+    assert!(!moves.iter().any(|mv| mv.flag == MoveFlag::EnPassant));
+
+    let moves = legal_moves("8/8/8/1Pp4r/K7/8/8/7k w - c6");
+    assert!(moves.iter().any(|mv| mv.flag == MoveFlag::EnPassant));
+}
+
+#[test]
+fn test_en_passant_takes_checking_pawn() {
+    let moves = legal_moves("8/8/8/2pP4/1K6/8/8/7k w - c6");
+    assert!(moves.iter().any(|mv| mv.flag == MoveFlag::EnPassant));
+}
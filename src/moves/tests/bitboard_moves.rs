@@ -0,0 +1,106 @@
+use crate::board::{Board, Field, Position};
+use crate::board::bitboard::BitBoard;
+use crate::board::piece::Color;
+use crate::board::piece::Color::{Black, White};
+use crate::board::piece::{PieceMoved, PieceState};
+use crate::board::piece::Type::{Bishop, KING, Knight, Pawn, Queen, Rook};
+use crate::moves;
+use crate::moves::MoveList;
+use crate::moves::tests::utils;
+use crate::test_utils::TestRng;
+
+#[test]
+fn test_queries_match_board() {
+    let board = Board::new();
+    let bit_board = BitBoard::from_board(&board);
+
+    for y in 0..8 {
+        for x in 0..8 {
+            let pos = Position {x, y};
+            assert_eq!(board.is_empty(&pos), bit_board.is_empty(&pos));
+            assert_eq!(board.is_color(&pos, &White), bit_board.is_color(&pos, &White));
+            assert_eq!(board.piece_at(&pos).map(|state| (state.piece_type, state.color)),
+                       bit_board.piece_at(&pos).map(|state| (state.piece_type, state.color)));
+        }
+    }
+    assert_eq!(board.castling_rights(), bit_board.castling_rights());
+    assert_eq!(0xFFFF00000000FFFF, bit_board.occupied());
+    assert_eq!(0x00FF000000000000, bit_board.pieces(Pawn, &Black));
+    assert_eq!(0x0000000000000081, bit_board.pieces(Rook, &White));
+    assert_eq!(0x1000000000000000, bit_board.pieces(KING, &Black));
+    assert!(bit_board.to_board().castling_rights() == board.castling_rights());
+}
+
+#[test]
+fn test_random_games_match_board() {
+    let mut rng = TestRng(0x9E3779B97F4A7C15);
+    for _ in 0..8 {
+        let mut board = Board::new();
+        let mut bit_board = BitBoard::new();
+        let mut color = Color::White;
+
+        for _ in 0..80 {
+            let mut expected = MoveList::new();
+            moves::get_all_legal_moves_by_filtering(&board, &color, &mut expected);
+            let mut actual = MoveList::new();
+            bit_board.get_legal_moves(&color, &mut actual);
+            assert_eq!(utils::move_keys(&expected), utils::move_keys(&actual));
+            if expected.is_empty() {
+                break;
+            }
+
+            let mv = rng.pick(&expected).clone();
+            board.make_move(&mv);
+            bit_board.make_move(&mv);
+            assert!(bit_board == BitBoard::from_board(&board), "boards differ after {:?}", mv);
+            color = color.opposite();
+        }
+    }
+}
+
+#[test]
+fn test_special_moves_match_board() {
+    let mut board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Rook), Position {x: 0, y: 0}),
+        (Field::new_white(Rook), Position {x: 7, y: 0}),
+        (Field::Piece(PieceState {piece_type: Pawn, color: White, moved: PieceMoved::Yes}), Position {x: 4, y: 4}),
+        (Field::Piece(PieceState {piece_type: Pawn, color: White, moved: PieceMoved::Yes}), Position {x: 1, y: 6}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+        (Field::new_black(Rook), Position {x: 7, y: 7}),
+        (Field::new_black(Knight), Position {x: 0, y: 7}),
+        (Field::new_black(Bishop), Position {x: 6, y: 3}),
+        (Field::new_black(Queen), Position {x: 0, y: 2}),
+        (Field::new_black(Pawn), Position {x: 3, y: 6}),
+    ]);
+    board.move_piece(&Position {x: 3, y: 6}, &Position {x: 3, y: 4});
+    let bit_board = BitBoard::from_board(&board);
+
+    for color in [White, Black] {
+        let mut expected = MoveList::new();
+        moves::get_all_legal_moves_by_filtering(&board, &color, &mut expected);
+        let mut actual = MoveList::new();
+        bit_board.get_legal_moves(&color, &mut actual);
+        assert_eq!(utils::move_keys(&expected), utils::move_keys(&actual));
+        assert!(actual.iter().any(|mv| mv.flag == moves::MoveFlag::EnPassant || mv.is_castling()));
+    }
+}
+
+#[test]
+fn test_pawns_on_last_row_have_no_moves() {
+    let board = Board::new_from(vec![
+        (Field::new_white(Pawn), Position {x: 7, y: 7}),
+        (Field::new_black(Pawn), Position {x: 0, y: 0}),
+    ]);
+    let bit_board = BitBoard::from_board(&board);
+
+    for color in [White, Black] {
+        let mut moves = MoveList::new();
+        bit_board.get_moves(&color, &mut moves);
+        assert!(moves.is_empty());
+
+        let mut legal_moves = MoveList::new();
+        moves::get_all_legal_moves(&board, &color, &mut legal_moves);
+        assert!(legal_moves.is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::board::{Board, Field, Position};
+use crate::board::piece::Color::White;
+use crate::board::piece::{PieceMoved, PieceState};
+use crate::board::piece::Type::{Bishop, KING, Knight, Rook};
+use crate::moves;
+use crate::moves::MoveList;
+use crate::moves::tests::utils;
+
+fn castling_board(extra: Vec<(Field, Position)>) -> Board {
+    let mut fields = vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Rook), Position {x: 0, y: 0}),
+        (Field::new_white(Rook), Position {x: 7, y: 0}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+    ];
+    fields.extend(extra);
+    return Board::new_from(fields);
+}
+
+#[test]
+fn test_castling_both_sides() {
+    let king_pos = Position {x: 4, y: 0};
+    let board = castling_board(vec![]);
+
+    let mut moves = MoveList::new();
+    moves::get_legal_moves(&board, &king_pos, &mut moves);
+    utils::assert_moves(&vec![
+        Position {x: 3, y: 0},
+        Position {x: 3, y: 1},
+        Position {x: 4, y: 1},
+        Position {x: 5, y: 1},
+        Position {x: 5, y: 0},
+        Position {x: 6, y: 0},
+        Position {x: 2, y: 0},
+    ], &moves);
+}
+
+#[test]
+fn test_no_castling_through_pieces() {
+    let king_pos = Position {x: 4, y: 0};
+    let board = castling_board(vec![
+        (Field::new_white(Knight), Position {x: 1, y: 0}),
+        (Field::new_black(Bishop), Position {x: 6, y: 0}),
+    ]);
+
+    let mut moves = MoveList::new();
+    moves::get_moves(&board, &king_pos, &mut moves);
+    assert!(!utils::has_move_to(&moves, &Position {x: 6, y: 0}));
+    assert!(!utils::has_move_to(&moves, &Position {x: 2, y: 0}));
+}
+
+#[test]
+fn test_no_castling_through_or_out_of_check() {
+    let king_pos = Position {x: 4, y: 0};
+    let through_check = castling_board(vec![
+        (Field::new_black(Rook), Position {x: 5, y: 5}),
+    ]);
+    let mut moves = MoveList::new();
+    moves::get_moves(&through_check, &king_pos, &mut moves);
+    assert!(!utils::has_move_to(&moves, &Position {x: 6, y: 0}));
+    assert!(utils::has_move_to(&moves, &Position {x: 2, y: 0}));
+
+    let out_of_check = castling_board(vec![
+        (Field::new_black(Rook), Position {x: 4, y: 5}),
+    ]);
+    moves.clear();
+    moves::get_moves(&out_of_check, &king_pos, &mut moves);
+    assert!(!utils::has_move_to(&moves, &Position {x: 6, y: 0}));
+    assert!(!utils::has_move_to(&moves, &Position {x: 2, y: 0}));
+}
+
+#[test]
+fn test_no_castling_with_moved_rook() {
+    let king_pos = Position {x: 4, y: 0};
+    let board = castling_board(vec![
+        (Field::Piece(PieceState {piece_type: Rook, color: White, moved: PieceMoved::Yes}), Position {x: 7, y: 0}),
+    ]);
+
+    let mut moves = MoveList::new();
+    moves::get_moves(&board, &king_pos, &mut moves);
+    assert!(!utils::has_move_to(&moves, &Position {x: 6, y: 0}));
+    assert!(utils::has_move_to(&moves, &Position {x: 2, y: 0}));
+}
+
+#[test]
+fn test_castling_moves_rook() {
+    let mut board = castling_board(vec![]);
+
+    board.move_piece(&Position {x: 4, y: 0}, &Position {x: 2, y: 0});
+    assert!(board.is_empty(&Position {x: 0, y: 0}));
+    assert!(board.is_empty(&Position {x: 4, y: 0}));
+    assert!(matches!(board.value_at(&Position {x: 3, y: 0}),
+        Field::Piece(state) if state.piece_type == Rook && state.moved == PieceMoved::Yes));
+
+    let king_pos = Position {x: 2, y: 0};
+    let mut moves = MoveList::new();
+    moves::get_moves(&board, &king_pos, &mut moves);
+    assert!(!utils::has_move_to(&moves, &Position {x: 4, y: 0}));
+}
@@ -0,0 +1,43 @@
+use crate::board::{Board, Field, Position};
+use crate::board::piece::Color::{Black, White};
+use crate::board::piece::Type::{KING, Pawn, Rook};
+use crate::moves;
+use crate::moves::{Move, MoveFlag, MoveList};
+
+#[test]
+fn test_generated_move_flags() {
+    let mut board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Rook), Position {x: 7, y: 0}),
+        (Field::new_white(Pawn), Position {x: 4, y: 1}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+        (Field::new_black(Pawn), Position {x: 3, y: 3}),
+    ]);
+    board.move_piece(&Position {x: 4, y: 1}, &Position {x: 4, y: 3});
+
+    let mut moves = MoveList::new();
+    moves::get_moves(&board, &Position {x: 3, y: 3}, &mut moves);
+    let en_passant = moves.iter().find(|mv| mv.flag == MoveFlag::EnPassant).unwrap();
+    assert_eq!(Position {x: 4, y: 2}, en_passant.to);
+    assert_eq!(Pawn, en_passant.captured.unwrap().piece_type);
+    assert_eq!(Black, en_passant.piece.color);
+
+    let mut moves = MoveList::new();
+    moves::get_moves(&board, &Position {x: 4, y: 0}, &mut moves);
+    let castle = moves.iter().find(|mv| mv.is_castling()).unwrap();
+    assert_eq!(MoveFlag::KingSideCastle, castle.flag);
+    assert_eq!(None, castle.captured);
+}
+
+#[test]
+fn test_infer_matches_generated_moves() {
+    let board = Board::new();
+
+    for pos in board.positions_of(&White) {
+        let mut moves = MoveList::new();
+        moves::get_moves(&board, &pos, &mut moves);
+        for mv in &moves {
+            assert_eq!(Some(mv.clone()), Move::infer(&board, &mv.from, &mv.to, mv.promotion));
+        }
+    }
+}
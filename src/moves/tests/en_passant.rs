@@ -0,0 +1,74 @@
+use crate::board::{Board, Field, Position};
+use crate::board::piece::Color::White;
+use crate::board::piece::{PieceMoved, PieceState};
+use crate::board::piece::Type::{KING, Pawn, Rook};
+use crate::moves;
+use crate::moves::MoveList;
+use crate::moves::tests::utils;
+
+fn en_passant_board() -> Board {
+    let mut board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::Piece(PieceState {piece_type: Pawn, color: White, moved: PieceMoved::Yes}), Position {x: 4, y: 4}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+        (Field::new_black(Pawn), Position {x: 3, y: 6}),
+    ]);
+    board.move_piece(&Position {x: 3, y: 6}, &Position {x: 3, y: 4});
+    return board;
+}
+
+#[test]
+fn test_en_passant_after_double_step() {
+    let tested_pawn_pos = Position {x: 4, y: 4};
+    let board = en_passant_board();
+
+    let mut moves = MoveList::new();
+    moves::get_legal_moves(&board, &tested_pawn_pos, &mut moves);
+    utils::assert_moves(&vec![
+        Position {x: 4, y: 5},
+        Position {x: 3, y: 5},
+    ], &moves);
+}
+
+#[test]
+fn test_en_passant_removes_captured_pawn() {
+    let mut board = en_passant_board();
+
+    board.move_piece(&Position {x: 4, y: 4}, &Position {x: 3, y: 5});
+    assert!(board.is_empty(&Position {x: 3, y: 4}));
+    assert!(board.is_empty(&Position {x: 4, y: 4}));
+    assert!(board.is_color(&Position {x: 3, y: 5}, &White));
+}
+
+#[test]
+fn test_en_passant_expires_after_one_turn() {
+    let tested_pawn_pos = Position {x: 4, y: 4};
+    let mut board = en_passant_board();
+    board.move_piece(&Position {x: 4, y: 0}, &Position {x: 4, y: 1});
+    board.move_piece(&Position {x: 4, y: 7}, &Position {x: 4, y: 6});
+
+    let mut moves = MoveList::new();
+    moves::get_moves(&board, &tested_pawn_pos, &mut moves);
+    utils::assert_moves(&vec![
+        Position {x: 4, y: 5},
+    ], &moves);
+}
+
+#[test]
+fn test_en_passant_can_not_expose_king() {
+    let tested_pawn_pos = Position {x: 4, y: 4};
+    let mut board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 0, y: 4}),
+        (Field::Piece(PieceState {piece_type: Pawn, color: White, moved: PieceMoved::Yes}), tested_pawn_pos.clone()),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+        (Field::new_black(Rook), Position {x: 7, y: 4}),
+        (Field::new_black(Pawn), Position {x: 3, y: 6}),
+    ]);
+    board.move_piece(&Position {x: 3, y: 6}, &Position {x: 3, y: 4});
+
+    let mut moves = MoveList::new();
+    moves::get_legal_moves(&board, &tested_pawn_pos, &mut moves);
+    utils::assert_moves(&vec![
+        Position {x: 4, y: 5},
+    ], &moves);
+}
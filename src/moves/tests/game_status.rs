@@ -0,0 +1,54 @@
+use crate::board::{Board, Field, Position};
+use crate::board::piece::Color::{Black, White};
+use crate::board::piece::Type::{KING, Pawn, Queen, Rook};
+use crate::moves::game_status::GameStatus;
+
+#[test]
+fn test_start_position_is_ongoing() {
+    let board = Board::new();
+
+    assert_eq!(GameStatus::Ongoing, board.game_status(&White));
+    assert_eq!(GameStatus::Ongoing, board.game_status(&Black));
+}
+
+#[test]
+fn test_check() {
+    let board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+        (Field::new_black(Rook), Position {x: 4, y: 5}),
+    ]);
+
+    assert_eq!(GameStatus::Check, board.game_status(&White));
+    assert_eq!(GameStatus::Ongoing, board.game_status(&Black));
+}
+
+#[test]
+fn test_back_rank_checkmate() {
+    let board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Rook), Position {x: 0, y: 7}),
+        (Field::new_black(KING), Position {x: 6, y: 7}),
+        (Field::new_black(Pawn), Position {x: 5, y: 6}),
+        (Field::new_black(Pawn), Position {x: 6, y: 6}),
+        (Field::new_black(Pawn), Position {x: 7, y: 6}),
+    ]);
+
+    let status = board.game_status(&Black);
+    assert_eq!(GameStatus::Checkmate(White), status);
+    assert!(status.is_over());
+}
+
+#[test]
+fn test_stalemate() {
+    let board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Queen), Position {x: 1, y: 5}),
+        (Field::new_black(KING), Position {x: 0, y: 7}),
+    ]);
+
+    let status = board.game_status(&Black);
+    assert_eq!(GameStatus::Stalemate, status);
+    assert!(status.is_over());
+    assert_eq!(GameStatus::Ongoing, board.game_status(&White));
+}
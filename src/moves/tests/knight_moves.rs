@@ -1,8 +1,7 @@
 use crate::board::{Board, Field, Position};
-use crate::board::piece::Color::White;
-use crate::board::piece::{PieceMoved, PieceState};
 use crate::board::piece::Type::{Knight, Pawn};
 use crate::moves;
+use crate::moves::MoveList;
 use crate::moves::tests::utils;
 
 #[test]
@@ -12,7 +11,7 @@ fn test_knight_moves() {
         (Field::new_white(Knight), tested_knight_pos.clone()),
     ]);
 
-    let mut moves = Vec::new();
+    let mut moves = MoveList::new();
     moves::get_moves(&board, &tested_knight_pos, &mut moves);
     utils::assert_moves(&vec![
         Position {x: 0, y: 3},
@@ -29,7 +28,7 @@ fn test_knight_moves_2() {
         (Field::new_white(Knight), tested_knight_pos.clone()),
     ]);
 
-    let mut moves = Vec::new();
+    let mut moves = MoveList::new();
     moves::get_moves(&board, &tested_knight_pos, &mut moves);
     utils::assert_moves(&vec![
         Position {x: 6, y: 5},
@@ -53,7 +52,7 @@ fn test_knight_moves_3() {
         (Field::new_black(Pawn), Position{x: 6, y: 3})
     ]);
 
-    let mut moves = Vec::new();
+    let mut moves = MoveList::new();
     moves::get_moves(&board, &tested_knight_pos, &mut moves);
     utils::assert_moves(&vec![
         Position {x: 6, y: 3},
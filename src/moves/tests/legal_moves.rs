@@ -0,0 +1,69 @@
+use crate::board::{Board, Field, Position};
+use crate::board::piece::Type::{Bishop, KING, Knight, Pawn, Queen, Rook};
+use crate::moves;
+use crate::moves::MoveList;
+use crate::moves::tests::utils;
+
+#[test]
+fn test_pinned_knight_can_not_move() {
+    let tested_knight_pos = Position {x: 4, y: 2};
+    let board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Knight), tested_knight_pos.clone()),
+        (Field::new_black(Rook), Position {x: 4, y: 7}),
+    ]);
+
+    let mut moves = MoveList::new();
+    moves::get_legal_moves(&board, &tested_knight_pos, &mut moves);
+    utils::assert_moves(&vec![], &moves);
+}
+
+#[test]
+fn test_pinned_rook_moves_along_pin() {
+    let tested_rook_pos = Position {x: 4, y: 3};
+    let board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Rook), tested_rook_pos.clone()),
+        (Field::new_black(Queen), Position {x: 4, y: 5}),
+    ]);
+
+    let mut moves = MoveList::new();
+    moves::get_legal_moves(&board, &tested_rook_pos, &mut moves);
+    utils::assert_moves(&vec![
+        Position {x: 4, y: 1},
+        Position {x: 4, y: 2},
+        Position {x: 4, y: 4},
+        Position {x: 4, y: 5},
+    ], &moves);
+}
+
+#[test]
+fn test_king_can_not_step_into_attack() {
+    let tested_king_pos = Position {x: 0, y: 0};
+    let board = Board::new_from(vec![
+        (Field::new_white(KING), tested_king_pos.clone()),
+        (Field::new_black(Knight), Position {x: 2, y: 3}),
+        (Field::new_black(Bishop), Position {x: 3, y: 2}),
+        (Field::new_black(Pawn), Position {x: 1, y: 2}),
+    ]);
+
+    let mut moves = MoveList::new();
+    moves::get_legal_moves(&board, &tested_king_pos, &mut moves);
+    utils::assert_moves(&vec![], &moves);
+}
+
+#[test]
+fn test_check_must_be_resolved() {
+    let tested_knight_pos = Position {x: 2, y: 0};
+    let board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Knight), tested_knight_pos.clone()),
+        (Field::new_black(Queen), Position {x: 4, y: 3}),
+    ]);
+
+    let mut moves = MoveList::new();
+    moves::get_legal_moves(&board, &tested_knight_pos, &mut moves);
+    utils::assert_moves(&vec![
+        Position {x: 4, y: 1},
+    ], &moves);
+}
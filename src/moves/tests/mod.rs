@@ -3,3 +3,18 @@ mod utils;
 #[cfg(test)]
 mod pawn_moves;
 mod knight_moves;
+mod legal_moves;
+mod game_status;
+mod castling;
+mod en_passant;
+mod promotion;
+mod chess_move;
+mod attacks;
+mod bitboard_moves;
+mod perft;
+mod move_list;
+mod representation;
+mod bitboard_legal_moves;
+mod staged;
+mod san;
+mod uci;
@@ -0,0 +1,23 @@
+use crate::board::{Board, Position};
+use crate::board::piece::Color::White;
+use crate::moves;
+use crate::moves::MoveList;
+
+#[test]
+fn test_move_list_behaves_like_slice() {
+    let mut moves = MoveList::new();
+    assert!(moves.is_empty());
+    moves::get_all_legal_moves(&Board::new(), &White, &mut moves);
+    assert_eq!(20, moves.len());
+
+    moves.sort_by_key(|mv| (7 - mv.to.y, mv.to.x));
+    assert_eq!(Position {y: 3, x: 0}, moves[0].to);
+    assert_eq!(20, (&moves).into_iter().count());
+
+    let cloned = moves.clone();
+    assert!(cloned.iter().eq(moves.iter()));
+    assert_eq!(20, cloned.len());
+
+    moves.clear();
+    assert_eq!(0, moves.iter().count());
+}
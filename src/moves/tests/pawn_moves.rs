@@ -3,6 +3,7 @@ use crate::board::piece::Color::White;
 use crate::board::piece::{PieceMoved, PieceState};
 use crate::board::piece::Type::Pawn;
 use crate::moves;
+use crate::moves::MoveList;
 use crate::moves::tests::utils;
 
 #[test]
@@ -14,7 +15,7 @@ fn test_pawn_moves() {
         (Field::new_black(Pawn), Position{x: 2, y: 2}),
     ]);
 
-    let mut moves = Vec::new();
+    let mut moves = MoveList::new();
     moves::get_moves(&board, &tested_pawn_pos, &mut moves);
     utils::assert_moves(&vec![
         Position{x: 0, y: 2},
@@ -36,10 +37,10 @@ fn test_pawn_moves_2() {
         (Field::new_black(Pawn), enemy_right_top.clone()),
     ]);
 
-    let mut moves = Vec::new();
+    let mut moves = MoveList::new();
     moves::get_moves(&board, &tested_pawn_pos, &mut moves);
     let expected = vec![
-        tested_pawn_pos.delta_y(1),
+        tested_pawn_pos.delta(0, 1),
         enemy_left_top,
         enemy_right_top
     ];
@@ -48,14 +49,29 @@ fn test_pawn_moves_2() {
 
 #[test]
 fn test_pawn_moves_3() {
-    let tested_pawn_pos = Position{x: 1, y: 1};
+    let tested_pawn_pos = Position{x: 1, y: 2};
     let board=  Board::new_from(vec![
         (Field::Piece(PieceState{piece_type: Pawn, color: White, moved: PieceMoved::Yes}), tested_pawn_pos.clone())
     ]);
 
-    let mut moves = Vec::new();
+    let mut moves = MoveList::new();
+    moves::get_moves(&board, &tested_pawn_pos, &mut moves);
+    let expected = vec![tested_pawn_pos.delta(0, 1)];
+
+    utils::assert_moves(&expected, &moves)
+}
+
+#[test]
+fn test_pawn_double_step_blocked() {
+    let tested_pawn_pos = Position{x: 1, y: 1};
+    let board = Board::new_from(vec![
+        (Field::new_white(Pawn), tested_pawn_pos.clone()),
+        (Field::new_black(Pawn), Position{x: 1, y: 3}),
+    ]);
+
+    let mut moves = MoveList::new();
     moves::get_moves(&board, &tested_pawn_pos, &mut moves);
-    let expected = vec![tested_pawn_pos.delta_y(1)];
+    let expected = vec![tested_pawn_pos.delta(0, 1)];
 
     utils::assert_moves(&expected, &moves)
-}
\ No newline at end of file
+}
@@ -0,0 +1,92 @@
+use crate::board::Board;
+use crate::board::piece::Color::White;
+use crate::errors::ErrorKind;
+use crate::moves::parallel_perft::{parallel_perft, PerftTable};
+use crate::moves::perft::{divide, perft};
+
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -";
+const POSITION_3: &str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -";
+const POSITION_4: &str = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+const POSITION_4_MIRRORED: &str = "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1";
+const POSITION_5: &str = "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8";
+const POSITION_6: &str = "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10";
+
+fn assert_perft(fen: &str, expected: &[u64]) {
+    let (board, color) = Board::from_fen(fen).unwrap();
+    for (depth, nodes) in expected.iter().enumerate() {
+        assert_eq!(*nodes, perft(&board, &color, depth + 1), "{} at depth {}", fen, depth + 1);
+    }
+}
+
+#[test]
+fn test_perft_start_position() {
+    assert_eq!(1, perft(&Board::new(), &White, 0));
+    assert_perft("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -", &[20, 400, 8902]);
+}
+
+#[test]
+fn test_perft_kiwipete() {
+    assert_perft(KIWIPETE, &[48, 2039, 97862]);
+}
+
+#[test]
+fn test_perft_position_3() {
+    assert_perft(POSITION_3, &[14, 191, 2812, 43238]);
+}
+
+#[test]
+fn test_perft_position_4() {
+    assert_perft(POSITION_4, &[6, 264, 9467]);
+    assert_perft(POSITION_4_MIRRORED, &[6, 264, 9467]);
+}
+
+#[test]
+fn test_perft_position_5() {
+    assert_perft(POSITION_5, &[44, 1486, 62379]);
+}
+
+#[test]
+fn test_perft_position_6() {
+    assert_perft(POSITION_6, &[46, 2079, 89890]);
+}
+
+#[test]
+fn test_divide_adds_up_to_perft() {
+    let (board, color) = Board::from_fen(KIWIPETE).unwrap();
+    let counts = divide(&board, &color, 2);
+    assert_eq!(48, counts.len());
+    assert_eq!(2039, counts.iter().map(|(_, nodes)| nodes).sum::<u64>());
+}
+
+#[test]
+fn test_divide_matches_perft_at_each_depth() {
+    let (board, color) = Board::from_fen(KIWIPETE).unwrap();
+    assert!(divide(&board, &color, 0).is_empty());
+    for depth in 1..=3 {
+        let counts = divide(&board, &color, depth);
+        assert_eq!(perft(&board, &color, depth), counts.iter().map(|(_, nodes)| nodes).sum::<u64>(), "depth {}", depth);
+    }
+}
+
+#[test]
+fn test_parallel_perft_matches_perft() {
+    let (board, color) = Board::from_fen(KIWIPETE).unwrap();
+    assert_eq!(48, parallel_perft(&board, &color, 1, 4, None).nodes);
+    assert_eq!(97862, parallel_perft(&board, &color, 3, 4, None).nodes);
+    assert_eq!(8902, parallel_perft(&Board::new(), &White, 3, 1, None).nodes);
+}
+
+#[test]
+fn test_parallel_perft_with_table() {
+    let table = PerftTable::new(1).unwrap();
+    let (board, color) = Board::from_fen(POSITION_3).unwrap();
+    assert_eq!(43238, parallel_perft(&board, &color, 4, 3, Some(&table)).nodes);
+    assert_eq!(43238, parallel_perft(&board, &color, 4, 3, Some(&table)).nodes);
+    assert_eq!(197281, parallel_perft(&Board::new(), &White, 4, 3, Some(&table)).nodes);
+}
+
+#[test]
+fn test_perft_table_size_overflow_is_an_error() {
+    assert!(matches!(PerftTable::new(usize::MAX), Err(ErrorKind::PerftTableTooLarge)));
+    assert!(matches!(PerftTable::new(usize::MAX / (1024 * 1024)), Err(ErrorKind::PerftTableTooLarge)));
+}
@@ -0,0 +1,98 @@
+use crate::board::{Board, Field, Position};
+use crate::board::piece::Color::{Black, White};
+use crate::board::piece::{PieceMoved, PieceState};
+use crate::board::piece::Type::{Bishop, KING, Knight, Pawn, Queen, Rook};
+use crate::errors::ErrorKind;
+use crate::moves;
+use crate::moves::MoveList;
+use crate::moves::game_status::GameStatus;
+use crate::moves::tests::utils;
+
+fn promotion_board() -> Board {
+    return Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::Piece(PieceState {piece_type: Pawn, color: White, moved: PieceMoved::Yes}), Position {x: 1, y: 6}),
+        (Field::new_black(KING), Position {x: 7, y: 7}),
+        (Field::new_black(Rook), Position {x: 0, y: 7}),
+    ]);
+}
+
+#[test]
+fn test_pawn_on_last_row_has_no_moves() {
+    let tested_pawn_pos = Position {x: 3, y: 7};
+    let board = Board::new_from(vec![
+        (Field::new_white(Pawn), tested_pawn_pos.clone()),
+    ]);
+
+    let mut moves = MoveList::new();
+    moves::get_moves(&board, &tested_pawn_pos, &mut moves);
+    utils::assert_moves(&vec![], &moves);
+}
+
+#[test]
+fn test_pawn_on_last_row_has_no_legal_moves() {
+    let board = Board::new_from(vec![
+        (Field::new_white(KING), Position {x: 4, y: 0}),
+        (Field::new_white(Pawn), Position {x: 7, y: 7}),
+        (Field::new_black(KING), Position {x: 4, y: 7}),
+        (Field::new_black(Pawn), Position {x: 0, y: 0}),
+    ]);
+
+    for (color, pawn_pos) in [(White, Position {x: 7, y: 7}), (Black, Position {x: 0, y: 0})] {
+        let mut moves = MoveList::new();
+        moves::get_legal_moves(&board, &pawn_pos, &mut moves);
+        utils::assert_moves(&vec![], &moves);
+
+        let mut all_moves = MoveList::new();
+        moves::get_all_legal_moves(&board, &color, &mut all_moves);
+        assert!(all_moves.iter().all(|mv| mv.piece.piece_type == KING));
+        assert_eq!(GameStatus::Ongoing, board.game_status(&color));
+    }
+}
+
+#[test]
+fn test_promotion_moves() {
+    let board = promotion_board();
+
+    let mut moves = MoveList::new();
+    moves::get_legal_moves(&board, &Position {x: 1, y: 6}, &mut moves);
+    assert_eq!(8, moves.len());
+    for to in [Position {x: 0, y: 7}, Position {x: 1, y: 7}] {
+        for promotion in [Queen, Rook, Bishop, Knight] {
+            assert!(moves.iter().any(|mv| mv.to == to && mv.promotion == Some(promotion)));
+        }
+    }
+    assert!(moves.iter().filter(|mv| mv.is_capture()).all(|mv| mv.captured.unwrap().piece_type == Rook));
+}
+
+#[test]
+fn test_move_piece_with_promotion() {
+    let mut board = promotion_board();
+
+    let result = board.move_piece_with_promotion(&Position {x: 1, y: 6}, &Position {x: 0, y: 7}, Knight);
+    assert!(result.is_ok());
+    assert!(board.is_empty(&Position {x: 1, y: 6}));
+    assert!(matches!(board.value_at(&Position {x: 0, y: 7}),
+        Field::Piece(state) if state.piece_type == Knight && state.color == White));
+}
+
+#[test]
+fn test_move_piece_promotes_to_queen_by_default() {
+    let mut board = promotion_board();
+
+    board.move_piece(&Position {x: 1, y: 6}, &Position {x: 1, y: 7});
+    assert!(matches!(board.value_at(&Position {x: 1, y: 7}),
+        Field::Piece(state) if state.piece_type == Queen && state.color == White));
+}
+
+#[test]
+fn test_invalid_promotion() {
+    let mut board = promotion_board();
+
+    let to_king = board.move_piece_with_promotion(&Position {x: 1, y: 6}, &Position {x: 1, y: 7}, KING);
+    assert!(matches!(to_king, Err(ErrorKind::InvalidPromotion)));
+
+    let not_last_row = board.move_piece_with_promotion(&Position {x: 4, y: 0}, &Position {x: 4, y: 1}, Queen);
+    assert!(matches!(not_last_row, Err(ErrorKind::InvalidPromotion)));
+    assert!(board.is_color(&Position {x: 1, y: 6}, &White));
+}
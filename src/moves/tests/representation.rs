@@ -0,0 +1,85 @@
+use crate::board::{Board, BOARD_SIZE, Position};
+use crate::board::bitboard::BitBoard;
+use crate::board::piece::Color;
+use crate::board::representation::BoardRepresentation;
+use crate::moves;
+use crate::moves::MoveList;
+use crate::moves::perft::perft;
+use crate::test_utils::TestRng;
+
+/// Moves with everything they carry, sorted so that the order of generation does not matter.
+fn full_move_keys(moves: &MoveList) -> Vec<String> {
+    let mut keys: Vec<String> = moves.iter().map(|mv| format!("{:?}", mv)).collect();
+    keys.sort();
+    return keys
+}
+
+/// Plays random games on both representations, starting from the same position, and checks
+/// before every move that both see the same pieces and that the legal moves of the first one
+/// match those the second one finds by trying every pseudo-legal move.
+fn assert_same_games<A: BoardRepresentation, B: BoardRepresentation>(first: &A, second: &B, color: Color, seed: u64, games: usize, plies: usize) {
+    let mut rng = TestRng(seed);
+    for _ in 0..games {
+        let mut first = first.clone();
+        let mut second = second.clone();
+        let mut color = color;
+        for _ in 0..plies {
+            for square in 0..BOARD_SIZE {
+                let pos = Position::from_board_index(square);
+                assert_eq!(first.piece_at(&pos), second.piece_at(&pos));
+            }
+            assert_eq!(first.castling_rights(), second.castling_rights());
+            assert_eq!(first.en_passant_target(), second.en_passant_target());
+
+            let mut first_moves = MoveList::new();
+            moves::get_all_legal_moves(&first, &color, &mut first_moves);
+            let mut second_moves = MoveList::new();
+            moves::get_all_legal_moves_by_filtering(&second, &color, &mut second_moves);
+            assert_eq!(full_move_keys(&first_moves), full_move_keys(&second_moves));
+            if first_moves.is_empty() {
+                break;
+            }
+
+            let mv = rng.pick(&first_moves).clone();
+            first.make_move(&mv);
+            second.make_move(&mv);
+            color = color.opposite();
+        }
+    }
+}
+
+#[test]
+fn test_mailbox_and_bitboard_play_the_same_games() {
+    assert_same_games(&Board::new(), &BitBoard::new(), Color::White, 0xA0761D6478BD642F, 6, 150);
+}
+
+#[test]
+fn test_mailbox_and_bitboard_play_the_same_games_from_kiwipete() {
+    let (board, color) = Board::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -").unwrap();
+    assert_same_games(&board, &BitBoard::from_board(&board), color, 0xE7037ED1A0B428DB, 6, 100);
+}
+
+#[test]
+fn test_perft_on_both_representations() {
+    let (board, color) = Board::from_fen("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1").unwrap();
+    assert_eq!(9467, perft(&board, &color, 3));
+    assert_eq!(9467, perft(&BitBoard::from_board(&board), &color, 3));
+}
+
+#[test]
+fn test_move_piece_through_trait() {
+    fn play_scholars_mate<B: BoardRepresentation>(board: &mut B) {
+        for (from, to) in [((4, 1), (4, 3)), ((4, 6), (4, 4)), ((5, 0), (2, 3)), ((1, 7), (2, 5)),
+                           ((3, 0), (7, 4)), ((6, 7), (5, 5)), ((7, 4), (5, 6))] {
+            board.move_piece(&Position {x: from.0, y: from.1}, &Position {x: to.0, y: to.1});
+        }
+    }
+
+    let mut board = Board::new();
+    let mut bit_board = BitBoard::new();
+    play_scholars_mate(&mut board);
+    play_scholars_mate(&mut bit_board);
+    assert!(bit_board.to_board() == board);
+    assert!(moves::is_in_check(&board, &Color::Black));
+    assert!(moves::is_in_check(&bit_board, &Color::Black));
+}
@@ -0,0 +1,92 @@
+use crate::board::Board;
+use crate::board::piece::Color::White;
+use crate::board::piece::Type::Queen;
+use crate::errors::ErrorKind;
+use crate::moves::{MoveFlag, MoveList};
+use crate::moves;
+use crate::moves::san::{parse_san, to_san};
+
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -";
+
+fn san_of(fen: &str, san: &str) -> String {
+    let (board, color) = Board::from_fen(fen).unwrap();
+    return to_san(&board, &parse_san(&board, &color, san).unwrap())
+}
+
+#[test]
+fn test_every_move_reads_back() {
+    for fen in [KIWIPETE, "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -"] {
+        let (board, color) = Board::from_fen(fen).unwrap();
+        let mut legal_moves = MoveList::new();
+        moves::get_all_legal_moves(&board, &color, &mut legal_moves);
+        for mv in &legal_moves {
+            let san = to_san(&board, mv);
+            assert_eq!(Ok(mv.clone()), parse_san(&board, &color, &san), "{}", san);
+        }
+    }
+}
+
+#[test]
+fn test_pieces_captures_and_castling() {
+    let (board, color) = Board::from_fen(KIWIPETE).unwrap();
+    let castle = parse_san(&board, &color, "O-O-O").unwrap();
+    assert_eq!(MoveFlag::QueenSideCastle, castle.flag);
+    assert_eq!("O-O-O", to_san(&board, &castle));
+    assert_eq!(MoveFlag::KingSideCastle, parse_san(&board, &color, "0-0").unwrap().flag);
+    assert_eq!("Nxf7", san_of(KIWIPETE, "Nxf7"));
+    assert_eq!("dxe6", san_of(KIWIPETE, "dxe6"));
+    assert_eq!("Qxf6", san_of(KIWIPETE, "Qf6"));
+    assert_eq!("a4", san_of(KIWIPETE, "a4!?"));
+}
+
+#[test]
+fn test_disambiguation() {
+    let knights = "4k3/8/8/8/8/8/8/1N2KN2 w - -";
+    assert_eq!("Nbd2", san_of(knights, "Nbd2"));
+    assert_eq!("Nfd2", san_of(knights, "Nf1d2"));
+    let rooks = "4k3/8/8/R7/8/8/8/R3K3 w - -";
+    assert_eq!("R1a3", san_of(rooks, "R1a3"));
+    assert_eq!("R5a3", san_of(rooks, "R5xa3"));
+    let queens = "4k3/8/8/8/8/Q7/8/Q1Q1K3 w - -";
+    assert_eq!("Qa1b2", san_of(queens, "Qa1b2"));
+    assert_eq!("Qcb2", san_of(queens, "Qcb2"));
+    assert_eq!("Q3b2", san_of(queens, "Q3b2"));
+}
+
+#[test]
+fn test_check_and_mate_suffixes() {
+    let fools_mate = "rnbqkbnr/pppp1ppp/8/4p3/6P1/5P2/PPPPP2P/RNBQKBNR b KQkq g3 0 2";
+    assert_eq!("Qh4#", san_of(fools_mate, "Qh4"));
+    assert_eq!("Bb4+", san_of("rnbqkbnr/pppp1ppp/8/4p3/8/3P4/PPP1PPPP/RNBQKBNR b KQkq - 0 2", "Bb4"));
+}
+
+#[test]
+fn test_en_passant_and_promotion() {
+    let en_passant = "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3";
+    let (board, color) = Board::from_fen(en_passant).unwrap();
+    let mv = parse_san(&board, &color, "dxe3 e.p.").unwrap();
+    assert_eq!(MoveFlag::EnPassant, mv.flag);
+    assert_eq!("dxe3", to_san(&board, &mv));
+
+    let promotion = "k7/4P3/8/8/8/8/8/4K3 w - -";
+    let (board, color) = Board::from_fen(promotion).unwrap();
+    assert_eq!(Some(Queen), parse_san(&board, &color, "e8Q").unwrap().promotion);
+    assert_eq!("e8=Q+", san_of(promotion, "e8=Q+"));
+    assert_eq!("e8=N", san_of(promotion, "e8=N"));
+    assert_eq!(Err(ErrorKind::IllegalMove), parse_san(&board, &color, "e8"));
+    assert_eq!(Err(ErrorKind::InvalidSan), parse_san(&board, &color, "e8=K"));
+}
+
+#[test]
+fn test_invalid_san() {
+    let board = Board::new();
+    assert_eq!(Err(ErrorKind::InvalidSan), parse_san(&board, &White, "Nf9"));
+    assert_eq!(Err(ErrorKind::InvalidSan), parse_san(&board, &White, "hello"));
+    assert_eq!(Err(ErrorKind::InvalidSan), parse_san(&board, &White, ""));
+    assert_eq!(Err(ErrorKind::IllegalMove), parse_san(&board, &White, "e5"));
+    assert_eq!(Err(ErrorKind::IllegalMove), parse_san(&board, &White, "O-O"));
+    assert_eq!(Err(ErrorKind::IllegalMove), parse_san(&board, &White, "exd3"));
+    let knights = "4k3/8/8/8/8/8/8/1N2KN2 w - -";
+    let (board, color) = Board::from_fen(knights).unwrap();
+    assert_eq!(Err(ErrorKind::AmbiguousMove), parse_san(&board, &color, "Nd2"));
+}
@@ -0,0 +1,94 @@
+use crate::board::{Board, Position};
+use crate::board::piece::Color::{Black, White};
+use crate::moves;
+use crate::moves::{MoveList, MoveStage, StagedMoves};
+use crate::moves::staged::{get_all_legal_stage_moves, get_stage_moves};
+use crate::moves::tests::utils;
+
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -";
+
+#[test]
+fn test_stages_add_up_to_all_legal_moves() {
+    for fen in [KIWIPETE, "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+                "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -"] {
+        let (board, color) = Board::from_fen(fen).unwrap();
+        let mut all_moves = MoveList::new();
+        moves::get_all_legal_moves(&board, &color, &mut all_moves);
+
+        let mut staged_moves = MoveList::new();
+        for (stage, moves) in StagedMoves::new(&board, color, &MoveStage::ALL_MOVES) {
+            assert!(moves.iter().all(|mv| mv.is_capture() == (stage == MoveStage::Captures)));
+            for mv in &moves {
+                staged_moves.push(mv.clone());
+            }
+        }
+        assert_eq!(utils::move_keys(&all_moves), utils::move_keys(&staged_moves));
+    }
+}
+
+#[test]
+fn test_kiwipete_captures() {
+    let (board, color) = Board::from_fen(KIWIPETE).unwrap();
+    let mut captures = MoveList::new();
+    get_all_legal_stage_moves(&board, &color, MoveStage::Captures, &mut captures);
+    assert_eq!(8, captures.len());
+}
+
+#[test]
+fn test_staged_moves_are_generated_lazily() {
+    let board = Board::new();
+    let mut stages = StagedMoves::new(&board, White, &MoveStage::ALL_MOVES);
+    let (stage, captures) = stages.next().unwrap();
+    assert_eq!(MoveStage::Captures, stage);
+    assert!(captures.is_empty());
+    assert_eq!(20, stages.next().unwrap().1.len());
+    assert!(stages.next().is_none());
+}
+
+#[test]
+fn test_quiet_checks() {
+    let (board, color) = Board::from_fen("3k4/8/8/8/8/8/8/R3K2R w KQ -").unwrap();
+    let mut quiet_checks = MoveList::new();
+    get_all_legal_stage_moves(&board, &color, MoveStage::QuietChecks, &mut quiet_checks);
+    assert!(!quiet_checks.is_empty());
+    assert!(quiet_checks.iter().all(|mv| !mv.is_capture()));
+
+    let mut board_after_move = board.clone();
+    for mv in &quiet_checks {
+        let undo = board_after_move.make_move(mv);
+        assert!(moves::is_in_check(&board_after_move, &Black));
+        board_after_move.unmake_move(mv, undo);
+    }
+    // Rooks to a8, d1 and h8, and castling queen side which brings a rook to d1 as well.
+    assert_eq!(4, quiet_checks.len());
+    assert!(quiet_checks.iter().any(|mv| mv.is_castling()));
+
+    let mut rook_checks = MoveList::new();
+    get_stage_moves(&board, &Position {x: 0, y: 0}, MoveStage::QuietChecks, &mut rook_checks);
+    assert_eq!(2, rook_checks.len());
+}
+
+#[test]
+fn test_stages_match_filtered_legal_moves() {
+    for fen in [KIWIPETE, "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+                "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8", "8/8/8/2k5/3Pp3/8/8/4K2R b K d3"] {
+        let (board, color) = Board::from_fen(fen).unwrap();
+        let mut legal_moves = MoveList::new();
+        moves::get_all_legal_moves_by_filtering(&board, &color, &mut legal_moves);
+
+        for stage in [MoveStage::Captures, MoveStage::Quiets, MoveStage::QuietChecks] {
+            let mut expected = MoveList::new();
+            for mv in legal_moves.iter().filter(|mv| mv.is_capture() == (stage == MoveStage::Captures)) {
+                let mut board_after_move = board.clone();
+                board_after_move.make_move(mv);
+                if stage != MoveStage::QuietChecks || moves::is_in_check(&board_after_move, &color.opposite()) {
+                    expected.push(mv.clone());
+                }
+            }
+
+            let mut staged = MoveList::new();
+            get_all_legal_stage_moves(&board, &color, stage, &mut staged);
+            assert_eq!(utils::move_keys(&expected), utils::move_keys(&staged), "{:?} of {}", stage, fen);
+        }
+    }
+}
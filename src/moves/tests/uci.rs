@@ -0,0 +1,47 @@
+use crate::board::{Board, Position};
+use crate::board::piece::Color::White;
+use crate::board::piece::Type::{Knight, Queen};
+use crate::errors::ErrorKind;
+use crate::moves::{Move, MoveFlag, MoveList};
+use crate::moves;
+
+#[test]
+fn test_every_move_reads_back() {
+    let (board, color) = Board::from_fen("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1R1K b kq -").unwrap();
+    let mut legal_moves = MoveList::new();
+    moves::get_all_legal_moves(&board, &color, &mut legal_moves);
+    for mv in &legal_moves {
+        assert_eq!(Ok(mv.clone()), Move::from_uci(&board, &color, &mv.to_uci()), "{}", mv.to_uci());
+    }
+}
+
+#[test]
+fn test_special_moves() {
+    let board = Board::new();
+    let push = Move::from_uci(&board, &White, "e2e4").unwrap();
+    assert_eq!(MoveFlag::DoublePawnPush, push.flag);
+    assert_eq!("e2e4", push.to_uci());
+
+    let (board, color) = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq -").unwrap();
+    let castle = Move::from_uci(&board, &color, "e1c1").unwrap();
+    assert_eq!(MoveFlag::QueenSideCastle, castle.flag);
+
+    let (board, color) = Board::from_fen("4k3/1P6/8/8/8/8/8/4K3 w - -").unwrap();
+    let promotion = Move::from_uci(&board, &color, "b7b8n").unwrap();
+    assert_eq!((Position {x: 1, y: 7}, Some(Knight)), (promotion.to.clone(), promotion.promotion));
+    assert_eq!("b7b8n", promotion.to_uci());
+    assert_eq!(Some(Queen), Move::from_uci(&board, &color, "b7b8q").unwrap().promotion);
+}
+
+#[test]
+fn test_rejected_moves() {
+    let board = Board::new();
+    for uci in ["", "e2", "e2e", "e2e4qq", "e2e9", "i2e4", "e7e8k", "E2E4", "0000"] {
+        assert_eq!(Err(ErrorKind::InvalidUci), Move::from_uci(&board, &White, uci), "{}", uci);
+    }
+    for uci in ["e2e5", "e7e5", "e1g1", "e2e4q"] {
+        assert_eq!(Err(ErrorKind::IllegalMove), Move::from_uci(&board, &White, uci), "{}", uci);
+    }
+    let (board, color) = Board::from_fen("4k3/1P6/8/8/8/8/8/4K3 w - -").unwrap();
+    assert_eq!(Err(ErrorKind::IllegalMove), Move::from_uci(&board, &color, "b7b8"));
+}
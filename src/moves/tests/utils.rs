@@ -1,8 +1,23 @@
 use crate::board::Position;
+use crate::moves::Move;
 
-pub fn assert_moves(expected: &Vec<Position>, actual: &Vec<Position>) {
+pub fn assert_moves(expected: &Vec<Position>, actual: &[Move]) {
     assert_eq!(expected.len(), actual.len());
     for expected_pos in expected {
-        assert!(actual.iter().find(|pos| &expected_pos == pos).is_some());
+        assert!(actual.iter().any(|mv| expected_pos == &mv.to));
     }
 }
+
+pub fn has_move_to(moves: &[Move], pos: &Position) -> bool {
+    return moves.iter().any(|mv| &mv.to == pos)
+}
+
+/// Moves written without the moved state of the pieces, which representations may track differently,
+/// sorted so that lists from different generators can be compared.
+pub fn move_keys(moves: &[Move]) -> Vec<String> {
+    let mut keys: Vec<String> = moves.iter()
+        .map(|mv| format!("{:?}", (&mv.from, &mv.to, mv.flag, mv.promotion)))
+        .collect();
+    keys.sort();
+    return keys
+}
@@ -0,0 +1,35 @@
+use crate::board::Position;
+use crate::board::representation::BoardRepresentation;
+use crate::board::piece::{Color, Type};
+use crate::errors::ErrorKind;
+use crate::moves::{get_all_legal_moves, Move, MoveList};
+
+/// Long algebraic notation of the UCI protocol: the from and to squares followed by the lower case
+/// letter of the promotion, like "e2e4" or "e7e8q". Castling is written as the move of the king.
+impl Move {
+    pub fn to_uci(&self) -> String {
+        let promotion = self.promotion.map_or(String::new(), |promotion| promotion.letter().to_ascii_lowercase().to_string());
+        return format!("{}{}{}", self.from, self.to, promotion)
+    }
+
+    /// The legal move of `color` written as `uci`.
+    pub fn from_uci<B: BoardRepresentation>(board: &B, color: &Color, uci: &str) -> Result<Move, ErrorKind> {
+        if !uci.is_ascii() || !(4..=5).contains(&uci.len()) {
+            return Err(ErrorKind::InvalidUci)
+        }
+        let from: Position = uci[0..2].parse().map_err(|_| ErrorKind::InvalidUci)?;
+        let to: Position = uci[2..4].parse().map_err(|_| ErrorKind::InvalidUci)?;
+        let promotion = match uci[4..].chars().next() {
+            None => None,
+            Some(letter @ ('q' | 'r' | 'b' | 'n')) => Type::from_letter(letter),
+            Some(_) => return Err(ErrorKind::InvalidUci),
+        };
+
+        let mut legal_moves = MoveList::new();
+        get_all_legal_moves(board, color, &mut legal_moves);
+        return legal_moves.iter()
+            .find(|mv| mv.from == from && mv.to == to && mv.promotion == promotion)
+            .cloned()
+            .ok_or(ErrorKind::IllegalMove)
+    }
+}
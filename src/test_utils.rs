@@ -0,0 +1,15 @@
+/// Small xorshift generator, so that "random" positions and games in tests are the same on every run.
+pub struct TestRng(pub u64);
+
+impl TestRng {
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        return self.0
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return &items[(self.next() % items.len() as u64) as usize]
+    }
+}